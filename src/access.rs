@@ -0,0 +1,263 @@
+//! Accessors and navigation helpers for [MessyJsonValue](MessyJsonValue) and [MessyJsonValueRaw](MessyJsonValueRaw),
+//! mirroring those of [serde_json::Value](serde_json::Value).
+use super::*;
+use std::ops::Index;
+
+impl<'a> MessyJsonValue<'a> {
+    /// Get the string if this value is a string
+    #[inline]
+    pub fn as_str(&self) -> Option<&str> {
+        match self {
+            MessyJsonValue::String(x) => Some(x.as_ref()),
+            _ => None,
+        }
+    }
+
+    /// Get the number if this value is a number
+    #[inline]
+    pub fn as_u128(&self) -> Option<u128> {
+        match self {
+            MessyJsonValue::Number(x) => Some(*x),
+            _ => None,
+        }
+    }
+
+    /// Get the boolean if this value is a boolean
+    #[inline]
+    pub fn as_bool(&self) -> Option<bool> {
+        match self {
+            MessyJsonValue::Bool(x) => Some(*x),
+            _ => None,
+        }
+    }
+
+    /// Get the `uuid` if this value is a `uuid`
+    #[cfg(feature = "uuid")]
+    #[inline]
+    pub fn as_uuid(&self) -> Option<&feat_uuid::Uuid> {
+        match self {
+            MessyJsonValue::Uuid(x) => Some(x.as_ref()),
+            _ => None,
+        }
+    }
+
+    /// Get the object if this value is an object
+    #[inline]
+    pub fn as_object(&self) -> Option<&MessyJsonObjectValue<'a>> {
+        match self {
+            MessyJsonValue::Obj(x) => Some(x),
+            _ => None,
+        }
+    }
+
+    /// Get the array if this value is an array
+    #[inline]
+    pub fn as_array(&self) -> Option<&MessyJsonArrayValue<'a>> {
+        match self {
+            MessyJsonValue::Array(x) => Some(x),
+            _ => None,
+        }
+    }
+
+    /// Get the mutable object if this value is an object
+    #[inline]
+    pub fn as_object_mut(&mut self) -> Option<&mut MessyJsonObjectValue<'a>> {
+        match self {
+            MessyJsonValue::Obj(x) => Some(x),
+            _ => None,
+        }
+    }
+
+    /// Get the mutable array if this value is an array
+    #[inline]
+    pub fn as_array_mut(&mut self) -> Option<&mut MessyJsonArrayValue<'a>> {
+        match self {
+            MessyJsonValue::Array(x) => Some(x),
+            _ => None,
+        }
+    }
+
+    /// Check if this value was explicitly set to `null`
+    ///
+    /// An [absent](MessyJsonNullType::Absent) value is not considered `null`, see [is_absent](MessyJsonValue::is_absent)
+    #[inline]
+    pub fn is_null(&self) -> bool {
+        matches!(self, MessyJsonValue::Null(MessyJsonNullType::Null, _))
+    }
+
+    /// Check if this value was absent from the document
+    #[inline]
+    pub fn is_absent(&self) -> bool {
+        matches!(self, MessyJsonValue::Null(MessyJsonNullType::Absent, _))
+    }
+
+    /// Get the value of a key if this value is an object
+    #[inline]
+    pub fn get(&self, key: &str) -> Option<&MessyJsonValue<'a>> {
+        self.as_object().and_then(|x| x.get(key))
+    }
+
+    /// Look up a value using a [JSON Pointer](https://tools.ietf.org/html/rfc6901)
+    ///
+    /// ```rust
+    /// # use messy_json::*;
+    /// # use std::borrow::Cow;
+    /// let val = MessyJsonValue::Array(MessyJsonArrayValue::from(vec![
+    ///     MessyJsonValue::String(Cow::Borrowed("hello")),
+    /// ]));
+    ///
+    /// assert_eq!(val.pointer("/0").and_then(MessyJsonValue::as_str), Some("hello"));
+    /// assert_eq!(val.pointer("/1"), None);
+    /// ```
+    pub fn pointer(&self, pointer: &str) -> Option<&MessyJsonValue<'a>> {
+        pointer::split(pointer)?.try_fold(self, |target, token| match target {
+            MessyJsonValue::Obj(x) => x.get(token.as_ref()),
+            MessyJsonValue::Array(x) => x.get(pointer::parse_index(&token)?),
+            _ => None,
+        })
+    }
+
+    /// Get the mutable value of a key if this value is an object
+    #[inline]
+    pub fn get_mut(&mut self, key: &str) -> Option<&mut MessyJsonValue<'a>> {
+        self.as_object_mut().and_then(|x| x.get_mut(key))
+    }
+
+    /// Look up a mutable value using a [JSON Pointer](https://tools.ietf.org/html/rfc6901)
+    ///
+    /// The value is not checked against any schema once modified.
+    /// See [MessyJsonValueMut](MessyJsonValueMut) for schema-checked mutations.
+    pub fn pointer_mut(&mut self, pointer: &str) -> Option<&mut MessyJsonValue<'a>> {
+        pointer::split(pointer)?.try_fold(self, |target, token| match target {
+            MessyJsonValue::Obj(x) => x.get_mut(token.as_ref()),
+            MessyJsonValue::Array(x) => x.get_mut(pointer::parse_index(&token)?),
+            _ => None,
+        })
+    }
+}
+
+impl<'a> Index<&str> for MessyJsonValue<'a> {
+    type Output = MessyJsonValue<'a>;
+
+    /// Get the value of a key
+    ///
+    /// # Panics
+    ///
+    /// Panics if this value is not an object or if the key doesn't exist
+    fn index(&self, key: &str) -> &Self::Output {
+        self.get(key)
+            .unwrap_or_else(|| panic!("The key `{}` doesn't exist", key))
+    }
+}
+
+impl<'a> Index<usize> for MessyJsonValue<'a> {
+    type Output = MessyJsonValue<'a>;
+
+    /// Get an element of an array
+    ///
+    /// # Panics
+    ///
+    /// Panics if this value is not an array or if the index is out of bound
+    fn index(&self, idx: usize) -> &Self::Output {
+        self.as_array()
+            .and_then(|x| x.get(idx))
+            .unwrap_or_else(|| panic!("The index `{}` doesn't exist", idx))
+    }
+}
+
+impl<'a> MessyJsonValueRaw<'a> {
+    /// Get the string if this value is a string
+    #[inline]
+    pub fn as_str(&self) -> Option<&str> {
+        match self {
+            MessyJsonValueRaw::String(x) => Some(x.as_ref()),
+            _ => None,
+        }
+    }
+
+    /// Get the number if this value is a number
+    #[inline]
+    pub fn as_u128(&self) -> Option<u128> {
+        match self {
+            MessyJsonValueRaw::Number(x) => Some(*x),
+            _ => None,
+        }
+    }
+
+    /// Get the boolean if this value is a boolean
+    #[inline]
+    pub fn as_bool(&self) -> Option<bool> {
+        match self {
+            MessyJsonValueRaw::Bool(x) => Some(*x),
+            _ => None,
+        }
+    }
+
+    /// Get the object if this value is an object
+    #[inline]
+    pub fn as_object(&self) -> Option<&MessyJsonMap<Cow<'a, str>, MessyJsonValueRaw<'a>>> {
+        match self {
+            MessyJsonValueRaw::Obj(x) => Some(x),
+            _ => None,
+        }
+    }
+
+    /// Get the array if this value is an array
+    #[inline]
+    pub fn as_array(&self) -> Option<&Vec<MessyJsonValueRaw<'a>>> {
+        match self {
+            MessyJsonValueRaw::Array(x) => Some(x),
+            _ => None,
+        }
+    }
+
+    /// Check if this value is `null`
+    #[inline]
+    pub fn is_null(&self) -> bool {
+        matches!(self, MessyJsonValueRaw::Null)
+    }
+
+    /// Get the value of a key if this value is an object
+    #[inline]
+    pub fn get(&self, key: &str) -> Option<&MessyJsonValueRaw<'a>> {
+        self.as_object().and_then(|x| x.get(key))
+    }
+
+    /// Look up a value using a [JSON Pointer](https://tools.ietf.org/html/rfc6901)
+    pub fn pointer(&self, pointer: &str) -> Option<&MessyJsonValueRaw<'a>> {
+        pointer::split(pointer)?.try_fold(self, |target, token| match target {
+            MessyJsonValueRaw::Obj(x) => x.get(token.as_ref()),
+            MessyJsonValueRaw::Array(x) => x.get(pointer::parse_index(&token)?),
+            _ => None,
+        })
+    }
+}
+
+impl<'a> Index<&str> for MessyJsonValueRaw<'a> {
+    type Output = MessyJsonValueRaw<'a>;
+
+    /// Get the value of a key
+    ///
+    /// # Panics
+    ///
+    /// Panics if this value is not an object or if the key doesn't exist
+    fn index(&self, key: &str) -> &Self::Output {
+        self.get(key)
+            .unwrap_or_else(|| panic!("The key `{}` doesn't exist", key))
+    }
+}
+
+impl<'a> Index<usize> for MessyJsonValueRaw<'a> {
+    type Output = MessyJsonValueRaw<'a>;
+
+    /// Get an element of an array
+    ///
+    /// # Panics
+    ///
+    /// Panics if this value is not an array or if the index is out of bound
+    fn index(&self, idx: usize) -> &Self::Output {
+        self.as_array()
+            .and_then(|x| x.get(idx))
+            .unwrap_or_else(|| panic!("The index `{}` doesn't exist", idx))
+    }
+}
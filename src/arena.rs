@@ -0,0 +1,527 @@
+use super::*;
+use crate::schema::MessyJsonObjectTrait;
+use crate::schema_visitor::{messy_json_visit_map, messy_json_visit_seq};
+use crate::sink::{MessyJsonArraySink, MessyJsonObjectSink};
+use bumpalo::collections::Vec as BumpVec;
+use bumpalo::Bump;
+use std::ops::Index;
+
+/// ## Deserialized JSON Value allocated in an arena
+///
+/// Like [MessyJsonValue](MessyJsonValue), but the arrays, the objects and the strings that
+/// can't be borrowed from the input are allocated in a [Bump](bumpalo::Bump) arena, using
+/// [MessyJsonBuilder::in_arena](MessyJsonBuilder::in_arena).
+///
+/// Once the arena has grown to the size of the documents, a document is deserialized without
+/// going through the global allocator, except for the keys escaped in the input and the
+/// bookkeeping of aliases and projections. Resetting the arena between documents frees every
+/// node at once and reuses its memory for the next document.
+///
+/// The objects keep their keys in the order of the document, followed by the
+/// [absent](MessyJsonNullType::Absent) ones. Looking up a key is linear, which suits
+/// objects with a handful of keys.
+///
+/// ```rust
+/// # use messy_json::*;
+/// # use serde::de::DeserializeSeed;
+/// let schema = MessyJson::from(MessyJsonInner::Array(MessyJsonArray::new(
+///     MessyJson::from(MessyJsonInner::String(MessyJsonScalar::new(false))),
+///     false,
+/// )));
+/// let mut bump = bumpalo::Bump::new();
+///
+/// for input in [r#"["hello"]"#, r#"["world"]"#].iter() {
+///     {
+///         let mut deserializer = serde_json::Deserializer::from_str(input);
+///         let value = schema
+///             .builder(MessyJsonSettings::default())
+///             .in_arena(&bump)
+///             .deserialize(&mut deserializer)
+///             .unwrap();
+///
+///         assert_eq!(value[0].as_str(), Some(&input[2..7]));
+///     }
+///     bump.reset();
+/// }
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum MessyJsonArenaValue<'b> {
+    Array(BumpVec<'b, MessyJsonArenaValue<'b>>),
+    Bool(bool),
+    Number(u128),
+    Obj(MessyJsonArenaObject<'b>),
+    String(&'b str),
+    #[cfg(feature = "uuid")]
+    Uuid(feat_uuid::Uuid),
+    Null(MessyJsonNullType, MessyJsonExpected),
+}
+
+/// ## Deserialized JSON Object Value allocated in an arena
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct MessyJsonArenaObject<'b>(BumpVec<'b, (ArcStr, MessyJsonArenaValue<'b>)>);
+
+impl<'b> Deref for MessyJsonArenaObject<'b> {
+    type Target = [(ArcStr, MessyJsonArenaValue<'b>)];
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl<'b> MessyJsonArenaObject<'b> {
+    /// Get the value of a key
+    #[inline]
+    pub fn get(&self, key: &str) -> Option<&MessyJsonArenaValue<'b>> {
+        self.0
+            .iter()
+            .find(|(x, _)| x.as_str() == key)
+            .map(|(_, value)| value)
+    }
+
+    /// Check if a key is set, even to `null` or to an [absent](MessyJsonNullType::Absent) value
+    #[inline]
+    pub fn contains_key(&self, key: &str) -> bool {
+        self.get(key).is_some()
+    }
+
+    /// Take the inner value, consuming the object
+    pub fn take(self) -> BumpVec<'b, (ArcStr, MessyJsonArenaValue<'b>)> {
+        self.0
+    }
+}
+
+/// ## Object being deserialized in an arena
+///
+/// Keeps the position of each property of the schema in the object, so that checking if a
+/// property is set doesn't require looking it up.
+struct MessyJsonArenaObjectSink<'b> {
+    entries: BumpVec<'b, (ArcStr, MessyJsonArenaValue<'b>)>,
+    slots: BumpVec<'b, Option<usize>>,
+}
+
+impl<'b> MessyJsonArenaObjectSink<'b> {
+    fn new(obj: &MessyJsonObject, bump: &'b Bump) -> Self {
+        let len = obj.properties().len();
+        MessyJsonArenaObjectSink {
+            entries: BumpVec::with_capacity_in(len, bump),
+            slots: bumpalo::vec![in bump; None; len],
+        }
+    }
+
+    /// Set the value of a property, replacing its previous value
+    #[inline]
+    fn set(&mut self, position: usize, key: &ArcStr, value: MessyJsonArenaValue<'b>) {
+        match self.slots[position] {
+            Some(idx) => self.entries[idx].1 = value,
+            None => {
+                self.slots[position] = Some(self.entries.len());
+                self.entries.push((key.clone(), value));
+            }
+        }
+    }
+}
+
+impl<'de: 'b, 'b> MessyJsonObjectSink<'de> for MessyJsonArenaObjectSink<'b> {
+    type Seed = MessyJsonArenaBuilder<'b>;
+
+    #[inline]
+    fn seed(&self, builder: MessyJsonBuilder) -> Self::Seed {
+        builder.in_arena(self.entries.bump())
+    }
+
+    #[inline]
+    fn contains(&self, position: usize, _key: &str) -> bool {
+        self.slots[position].is_some()
+    }
+
+    #[inline]
+    fn is_null(&self, position: usize, _key: &str) -> bool {
+        matches!(self.slots[position], Some(idx) if self.entries[idx].1.is_null())
+    }
+
+    #[inline]
+    fn insert(&mut self, position: usize, key: &ArcStr, value: MessyJsonArenaValue<'b>) {
+        self.set(position, key, value);
+    }
+
+    #[inline]
+    fn insert_absent(&mut self, position: usize, key: &ArcStr, schema: &MessyJson) {
+        self.set(
+            position,
+            key,
+            MessyJsonArenaValue::Null(
+                MessyJsonNullType::Absent,
+                MessyJsonExpected::Root(schema.clone()),
+            ),
+        );
+    }
+
+    #[inline]
+    fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    fn retain<F: FnMut(&str) -> bool>(&mut self, mut f: F) {
+        let mut kept = 0;
+        let mut moved = BumpVec::with_capacity_in(self.entries.len(), self.entries.bump());
+        for (key, _) in self.entries.iter() {
+            moved.push(match f(key) {
+                true => {
+                    kept += 1;
+                    Some(kept - 1)
+                }
+                false => None,
+            });
+        }
+        let mut idx = 0;
+        self.entries.retain(|_| {
+            idx += 1;
+            moved[idx - 1].is_some()
+        });
+        for slot in self.slots.iter_mut() {
+            *slot = slot.and_then(|x| moved[x]);
+        }
+    }
+}
+
+impl<'de: 'b, 'b> MessyJsonArraySink<'de> for BumpVec<'b, MessyJsonArenaValue<'b>> {
+    type Seed = MessyJsonArenaBuilder<'b>;
+
+    #[inline]
+    fn seed(&self, builder: MessyJsonBuilder) -> Self::Seed {
+        builder.in_arena(self.bump())
+    }
+
+    #[inline]
+    fn push<E: serde::de::Error>(&mut self, value: MessyJsonArenaValue<'b>) -> Result<(), E> {
+        BumpVec::push(self, value);
+        Ok(())
+    }
+
+    #[inline]
+    fn len(&self) -> usize {
+        BumpVec::len(self)
+    }
+}
+
+impl<'b> MessyJsonArenaValue<'b> {
+    /// Move a [MessyJsonValue](MessyJsonValue) to an arena, copying its owned strings
+    pub fn new_in(value: MessyJsonValue<'b>, bump: &'b Bump) -> Self {
+        match value {
+            MessyJsonValue::Array(arr) => {
+                let mut res = BumpVec::with_capacity_in(arr.len(), bump);
+                res.extend(arr.take().into_iter().map(|x| Self::new_in(x, bump)));
+                MessyJsonArenaValue::Array(res)
+            }
+            MessyJsonValue::Obj(obj) => {
+                let mut res = BumpVec::with_capacity_in(obj.len(), bump);
+                res.extend(
+                    obj.take()
+                        .into_iter()
+                        .map(|(key, x)| (key, Self::new_in(x, bump))),
+                );
+                MessyJsonArenaValue::Obj(MessyJsonArenaObject(res))
+            }
+            MessyJsonValue::Bool(x) => MessyJsonArenaValue::Bool(x),
+            MessyJsonValue::Number(x) => MessyJsonArenaValue::Number(x),
+            MessyJsonValue::String(Cow::Borrowed(x)) => MessyJsonArenaValue::String(x),
+            MessyJsonValue::String(Cow::Owned(x)) => {
+                MessyJsonArenaValue::String(bump.alloc_str(x.as_str()))
+            }
+            #[cfg(feature = "uuid")]
+            MessyJsonValue::Uuid(x) => MessyJsonArenaValue::Uuid(*x),
+            MessyJsonValue::Null(null_type, expected) => {
+                MessyJsonArenaValue::Null(null_type, expected)
+            }
+        }
+    }
+
+    /// Convert to a [MessyJsonValue](MessyJsonValue), borrowing the strings from the arena
+    pub fn to_value(&self) -> MessyJsonValue<'b> {
+        match self {
+            MessyJsonArenaValue::Array(arr) => MessyJsonValue::Array(MessyJsonArrayValue::from(
+                arr.iter().map(Self::to_value).collect::<Vec<_>>(),
+            )),
+            MessyJsonArenaValue::Obj(obj) => MessyJsonValue::Obj(MessyJsonObjectValue::from(
+                obj.iter()
+                    .map(|(key, x)| (key.clone(), x.to_value()))
+                    .collect::<MessyJsonMap<_, _>>(),
+            )),
+            MessyJsonArenaValue::Bool(x) => MessyJsonValue::Bool(*x),
+            MessyJsonArenaValue::Number(x) => MessyJsonValue::Number(*x),
+            MessyJsonArenaValue::String(x) => MessyJsonValue::String(Cow::Borrowed(*x)),
+            #[cfg(feature = "uuid")]
+            MessyJsonArenaValue::Uuid(x) => MessyJsonValue::Uuid(Cow::Owned(*x)),
+            MessyJsonArenaValue::Null(null_type, expected) => {
+                MessyJsonValue::Null(null_type.clone(), expected.clone())
+            }
+        }
+    }
+
+    /// Get the string if this value is a string
+    #[inline]
+    pub fn as_str(&self) -> Option<&'b str> {
+        match self {
+            MessyJsonArenaValue::String(x) => Some(x),
+            _ => None,
+        }
+    }
+
+    /// Get the number if this value is a number
+    #[inline]
+    pub fn as_u128(&self) -> Option<u128> {
+        match self {
+            MessyJsonArenaValue::Number(x) => Some(*x),
+            _ => None,
+        }
+    }
+
+    /// Get the boolean if this value is a boolean
+    #[inline]
+    pub fn as_bool(&self) -> Option<bool> {
+        match self {
+            MessyJsonArenaValue::Bool(x) => Some(*x),
+            _ => None,
+        }
+    }
+
+    /// Get the `uuid` if this value is a `uuid`
+    #[cfg(feature = "uuid")]
+    #[inline]
+    pub fn as_uuid(&self) -> Option<&feat_uuid::Uuid> {
+        match self {
+            MessyJsonArenaValue::Uuid(x) => Some(x),
+            _ => None,
+        }
+    }
+
+    /// Get the object if this value is an object
+    #[inline]
+    pub fn as_object(&self) -> Option<&MessyJsonArenaObject<'b>> {
+        match self {
+            MessyJsonArenaValue::Obj(x) => Some(x),
+            _ => None,
+        }
+    }
+
+    /// Get the array if this value is an array
+    #[inline]
+    pub fn as_array(&self) -> Option<&[MessyJsonArenaValue<'b>]> {
+        match self {
+            MessyJsonArenaValue::Array(x) => Some(x),
+            _ => None,
+        }
+    }
+
+    /// Check if this value was explicitly set to `null`
+    #[inline]
+    pub fn is_null(&self) -> bool {
+        matches!(self, MessyJsonArenaValue::Null(MessyJsonNullType::Null, _))
+    }
+
+    /// Check if this value was absent from the document
+    #[inline]
+    pub fn is_absent(&self) -> bool {
+        matches!(
+            self,
+            MessyJsonArenaValue::Null(MessyJsonNullType::Absent, _)
+        )
+    }
+
+    /// Get the value of a key if this value is an object
+    #[inline]
+    pub fn get(&self, key: &str) -> Option<&MessyJsonArenaValue<'b>> {
+        self.as_object().and_then(|x| x.get(key))
+    }
+}
+
+impl<'b> Index<&str> for MessyJsonArenaValue<'b> {
+    type Output = MessyJsonArenaValue<'b>;
+
+    /// Get the value of a key
+    ///
+    /// # Panics
+    ///
+    /// Panics if this value is not an object or if the key doesn't exist
+    fn index(&self, key: &str) -> &Self::Output {
+        self.get(key)
+            .unwrap_or_else(|| panic!("The key `{}` doesn't exist", key))
+    }
+}
+
+impl<'b> Index<usize> for MessyJsonArenaValue<'b> {
+    type Output = MessyJsonArenaValue<'b>;
+
+    /// Get an element of an array
+    ///
+    /// # Panics
+    ///
+    /// Panics if this value is not an array or if the index is out of bound
+    fn index(&self, idx: usize) -> &Self::Output {
+        self.as_array()
+            .and_then(|x| x.get(idx))
+            .unwrap_or_else(|| panic!("The index `{}` doesn't exist", idx))
+    }
+}
+
+/// ## Schema deserializer into an arena
+///
+/// Deserialize a [MessyJsonArenaValue](MessyJsonArenaValue), checking it like the
+/// [MessyJsonBuilder](MessyJsonBuilder) it was created from.
+#[derive(Clone, Debug)]
+pub struct MessyJsonArenaBuilder<'b> {
+    builder: MessyJsonBuilder,
+    bump: &'b Bump,
+}
+
+impl MessyJsonBuilder {
+    /// Deserialize into a [MessyJsonArenaValue](MessyJsonArenaValue) allocated in `bump`
+    pub fn in_arena(self, bump: &Bump) -> MessyJsonArenaBuilder<'_> {
+        MessyJsonArenaBuilder {
+            builder: self,
+            bump,
+        }
+    }
+}
+
+impl<'b> MessyJsonArenaBuilder<'b> {
+    /// Deserialize a scalar using the [MessyJsonBuilder](MessyJsonBuilder), moving it to the arena
+    #[inline]
+    fn delegate<'de: 'b, F, E>(self, f: F) -> Result<MessyJsonArenaValue<'b>, E>
+    where
+        F: FnOnce(MessyJsonBuilder) -> Result<MessyJsonValueContainer<'de>, E>,
+    {
+        let bump = self.bump;
+        f(self.builder).map(|x| MessyJsonArenaValue::new_in(x.take(), bump))
+    }
+}
+
+impl<'de: 'b, 'b> DeserializeSeed<'de> for MessyJsonArenaBuilder<'b> {
+    type Value = MessyJsonArenaValue<'b>;
+
+    #[inline]
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        self.builder.prepare()?.deserialize(deserializer, self)
+    }
+}
+
+impl<'de: 'b, 'b> Visitor<'de> for MessyJsonArenaBuilder<'b> {
+    type Value = MessyJsonArenaValue<'b>;
+
+    #[inline]
+    fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(formatter, "any valid json object or array")
+    }
+
+    #[inline]
+    fn visit_seq<A>(self, seq: A) -> Result<Self::Value, A::Error>
+    where
+        A: SeqAccess<'de>,
+    {
+        let arr_type = match self.builder.inner().deref() {
+            MessyJsonInner::Array(x) => x,
+            _ => {
+                return Err(serde::de::Error::invalid_type(
+                    serde::de::Unexpected::Seq,
+                    &"Sequence",
+                ))
+            }
+        };
+        let res = BumpVec::with_capacity_in(seq.size_hint().unwrap_or(0), self.bump);
+        messy_json_visit_seq(seq, &self.builder, arr_type, res).map(MessyJsonArenaValue::Array)
+    }
+
+    fn visit_map<A>(self, seq: A) -> Result<Self::Value, A::Error>
+    where
+        A: MapAccess<'de>,
+    {
+        let obj = match self.builder.inner().deref() {
+            MessyJsonInner::Obj(x) => x,
+            _ => {
+                return Err(serde::de::Error::invalid_type(
+                    serde::de::Unexpected::Map,
+                    &"Map",
+                ))
+            }
+        };
+        let res = MessyJsonArenaObjectSink::new(obj, self.bump);
+        messy_json_visit_map(seq, &self.builder, obj, res)
+            .map(|x| MessyJsonArenaValue::Obj(MessyJsonArenaObject(x.entries)))
+    }
+
+    #[inline]
+    fn visit_bool<A>(self, v: bool) -> Result<Self::Value, A>
+    where
+        A: serde::de::Error,
+    {
+        self.delegate(|x| x.visit_bool(v))
+    }
+
+    #[inline]
+    fn visit_borrowed_str<A>(self, v: &'de str) -> Result<Self::Value, A>
+    where
+        A: serde::de::Error,
+    {
+        self.delegate(|x| x.visit_borrowed_str(v))
+    }
+
+    #[inline]
+    fn visit_str<A>(self, v: &str) -> Result<Self::Value, A>
+    where
+        A: serde::de::Error,
+    {
+        match self.builder.inner().deref() {
+            MessyJsonInner::String(_) => {
+                self.builder
+                    .settings()
+                    .limits()
+                    .check_string_length(v.len())?;
+                Ok(MessyJsonArenaValue::String(self.bump.alloc_str(v)))
+            }
+            _ => self.delegate(|x| x.visit_str(v)),
+        }
+    }
+
+    #[inline]
+    fn visit_string<A>(self, v: String) -> Result<Self::Value, A>
+    where
+        A: serde::de::Error,
+    {
+        self.visit_str(v.as_str())
+    }
+
+    #[inline]
+    fn visit_u64<A>(self, v: u64) -> Result<Self::Value, A>
+    where
+        A: serde::de::Error,
+    {
+        self.delegate(|x| x.visit_u64(v))
+    }
+
+    #[inline]
+    fn visit_u128<A>(self, v: u128) -> Result<Self::Value, A>
+    where
+        A: serde::de::Error,
+    {
+        self.delegate(|x| x.visit_u128(v))
+    }
+
+    #[inline]
+    fn visit_none<A>(self) -> Result<Self::Value, A>
+    where
+        A: serde::de::Error,
+    {
+        self.delegate(|x| x.visit_none())
+    }
+
+    #[inline]
+    fn visit_some<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        self.builder.hint().deserialize(deserializer, self)
+    }
+}
@@ -33,4 +33,14 @@ impl MessyJsonArray {
     pub fn optional(&self) -> bool {
         self.optional
     }
+
+    #[inline]
+    pub(crate) fn items_mut(&mut self) -> &mut MessyJson {
+        &mut self.items
+    }
+
+    #[inline]
+    pub(crate) fn set_optional(&mut self, optional: bool) {
+        self.optional = optional;
+    }
 }
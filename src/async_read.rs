@@ -0,0 +1,73 @@
+use super::*;
+use crate::schema::MessyJsonObjectTrait;
+use tokio::io::{AsyncRead, AsyncReadExt};
+
+/// Size of the buffer of the bytes read from an [AsyncRead](tokio::io::AsyncRead)
+const CHUNK_SIZE: usize = 8 * 1024;
+
+/// Blocking [Read](std::io::Read) over an [AsyncRead](tokio::io::AsyncRead), polling it on
+/// the runtime. Must only be used on a blocking thread.
+struct SyncReader<R> {
+    reader: R,
+    handle: tokio::runtime::Handle,
+    limits: MessyJsonLimits,
+    /// Number of bytes read so far
+    len: usize,
+}
+
+impl<R: AsyncRead + Unpin> std::io::Read for SyncReader<R> {
+    fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+        let reader = &mut self.reader;
+        let read = self.handle.block_on(reader.read(buf))?;
+        self.len += read;
+        match self.limits.document_size_exceeded(self.len) {
+            Some(exceeded) => Err(std::io::Error::new(
+                std::io::ErrorKind::InvalidData,
+                exceeded,
+            )),
+            None => Ok(read),
+        }
+    }
+}
+
+impl MessyJsonBuilder {
+    /// Read a document from an [AsyncRead](tokio::io::AsyncRead), validating it as it's read.
+    ///
+    /// The document is parsed on a blocking thread of the current runtime (see
+    /// [spawn_blocking](tokio::task::spawn_blocking)), pulling the chunks of the reader as the
+    /// parsing goes, so the input is never buffered as a whole and an invalid document fails
+    /// without being read until its end.
+    ///
+    /// Each call holds a thread of the blocking pool (512 threads by default) until the document
+    /// is read, waiting for the reader in between. Slow or stalled clients can therefore exhaust
+    /// the pool and delay every other blocking task. Dropping the returned future doesn't release
+    /// the thread: untrusted readers should be bounded in size, using
+    /// [take](tokio::io::AsyncReadExt::take) or
+    /// [max_document_size](MessyJsonLimits::max_document_size), and fail after a while when
+    /// they stall.
+    ///
+    /// Every string of the resulting value is owned. Validation errors are reported as
+    /// [InvalidData](std::io::ErrorKind::InvalidData) errors.
+    ///
+    /// Must be called from a `tokio` runtime.
+    pub async fn deserialize_async<R>(self, reader: R) -> std::io::Result<MessyJsonValue<'static>>
+    where
+        R: AsyncRead + Unpin + Send + 'static,
+    {
+        let handle = tokio::runtime::Handle::current();
+        tokio::task::spawn_blocking(move || {
+            let reader = SyncReader {
+                reader,
+                handle,
+                limits: *self.settings().limits(),
+                len: 0,
+            };
+            let reader = std::io::BufReader::with_capacity(CHUNK_SIZE, reader);
+            let mut deserializer = serde_json::Deserializer::from_reader(reader);
+            let res = self.deserialize(&mut deserializer)?;
+            deserializer.end()?;
+            Ok(res.take())
+        })
+        .await?
+    }
+}
@@ -4,6 +4,7 @@ use std::time::Duration;
 
 extern crate messy_json;
 use messy_json::*;
+mod vs_serde_array;
 mod vs_serde_dummy_obj;
 mod vs_serde_obj;
 mod vs_serde_optional_obj;
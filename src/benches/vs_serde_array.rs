@@ -0,0 +1,110 @@
+use super::*;
+use serde::{de::DeserializeSeed, Deserialize, Serialize};
+use serde_json::Value;
+
+const ITEM: &str = r#"{ "e": "5", "d": "4", "b": "2", "a": "1" }"#;
+
+#[derive(Serialize, Deserialize)]
+struct ArraySerdeItem<'a> {
+    a: Cow<'a, str>,
+    b: Cow<'a, str>,
+    c: Option<Cow<'a, str>>,
+    d: Cow<'a, str>,
+    e: Cow<'a, str>,
+}
+
+fn gen_array_obj() -> String {
+    format!("[{}]", vec![ITEM; 100].join(","))
+}
+
+fn parse_serde_array_obj<T: serde::de::DeserializeOwned>(input: &str) -> T {
+    serde_json::from_str(input).unwrap()
+}
+
+fn parse_serde_value_array_obj(input: &str) -> Value {
+    serde_json::from_str(input).unwrap()
+}
+
+fn parse_serde_raw_value_array_obj(input: &str) -> MessyJsonValueRaw<'_> {
+    serde_json::from_str(input).unwrap()
+}
+
+fn gen_messy_json_schema_array_obj() -> MessyJson {
+    let string = |optional| MessyJson::from(MessyJsonInner::String(MessyJsonScalar::new(optional)));
+    MessyJson::from(MessyJsonInner::Array(MessyJsonArray::new(
+        MessyJson::from(MessyJsonInner::Obj(MessyJsonObject::from(
+            MessyJsonObjectInner::new(
+                vec![
+                    (arcstr::literal!("a"), string(false)),
+                    (arcstr::literal!("b"), string(false)),
+                    (arcstr::literal!("c"), string(true)),
+                    (arcstr::literal!("d"), string(false)),
+                    (arcstr::literal!("e"), string(false)),
+                ]
+                .into_iter()
+                .collect(),
+                false,
+            ),
+        ))),
+        false,
+    )))
+}
+
+fn parse_messy_json_array_obj(schema: &MessyJson, input: &str) {
+    let mut deserializer = serde_json::Deserializer::from_str(input);
+    let _parsed: MessyJsonValueContainer = schema
+        .builder(MessyJsonSettings::default())
+        .deserialize(&mut deserializer)
+        .unwrap();
+}
+
+#[cfg(feature = "bumpalo")]
+fn parse_messy_json_arena_array_obj(schema: &MessyJson, input: &str, bump: &mut bumpalo::Bump) {
+    {
+        let mut deserializer = serde_json::Deserializer::from_str(input);
+        let _parsed: MessyJsonArenaValue = schema
+            .builder(MessyJsonSettings::default())
+            .in_arena(bump)
+            .deserialize(&mut deserializer)
+            .unwrap();
+    }
+    bump.reset();
+}
+
+pub fn criterion_benchmark(c: &mut Criterion) {
+    let prepared_array = gen_messy_json_schema_array_obj();
+    let array_obj = gen_array_obj();
+
+    let mut group = c.benchmark_group("Array of objects");
+    super::apply_criterion_group_settings(&mut group);
+    group.bench_with_input(
+        criterion::BenchmarkId::new("deser_serde_struct", "array_obj"),
+        array_obj.as_str(),
+        |b, i| b.iter(|| parse_serde_array_obj::<Vec<ArraySerdeItem>>(i)),
+    );
+    group.bench_with_input(
+        criterion::BenchmarkId::new("deser_serde_value", "array_obj"),
+        array_obj.as_str(),
+        |b, i| b.iter(|| parse_serde_value_array_obj(i)),
+    );
+    group.bench_with_input(
+        criterion::BenchmarkId::new("deser_messy_json", "array_obj"),
+        array_obj.as_str(),
+        |b, i| b.iter(|| parse_messy_json_array_obj(&prepared_array, i)),
+    );
+    #[cfg(feature = "bumpalo")]
+    group.bench_with_input(
+        criterion::BenchmarkId::new("deser_messy_json_arena", "array_obj"),
+        array_obj.as_str(),
+        |b, i| {
+            let mut bump = bumpalo::Bump::new();
+            b.iter(|| parse_messy_json_arena_array_obj(&prepared_array, i, &mut bump))
+        },
+    );
+    group.bench_with_input(
+        criterion::BenchmarkId::new("deser_messy_json_raw", "array_obj"),
+        array_obj.as_str(),
+        |b, i| b.iter(|| parse_serde_raw_value_array_obj(i)),
+    );
+    group.finish();
+}
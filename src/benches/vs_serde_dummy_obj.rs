@@ -28,7 +28,7 @@ fn parse_serde_value_dummy_obj(input: &str) -> Value {
     serde_json::from_str(input).unwrap()
 }
 
-fn parse_serde_raw_value_dummy_obj(input: &str) -> MessyJsonValueRaw {
+fn parse_serde_raw_value_dummy_obj(input: &str) -> MessyJsonValueRaw<'_> {
     serde_json::from_str(input).unwrap()
 }
 
@@ -64,6 +64,19 @@ fn parse_messy_json_dummy_obj(schema: &MessyJson) {
         .unwrap();
 }
 
+#[cfg(feature = "bumpalo")]
+fn parse_messy_json_arena_dummy_obj(schema: &MessyJson, bump: &mut bumpalo::Bump) {
+    {
+        let mut deserializer = serde_json::Deserializer::from_str(DUMMY_OBJ);
+        let _parsed: MessyJsonArenaValue = schema
+            .builder(MessyJsonSettings::default())
+            .in_arena(bump)
+            .deserialize(&mut deserializer)
+            .unwrap();
+    }
+    bump.reset();
+}
+
 pub fn criterion_benchmark(c: &mut Criterion) {
     let prepared_dummy = gen_messy_json_schema_dummy_obj();
 
@@ -84,6 +97,15 @@ pub fn criterion_benchmark(c: &mut Criterion) {
         &prepared_dummy,
         |b, _i| b.iter(|| parse_messy_json_dummy_obj(&prepared_dummy)),
     );
+    #[cfg(feature = "bumpalo")]
+    group.bench_with_input(
+        criterion::BenchmarkId::new("deser_messy_json_arena", "dummy_obj"),
+        &prepared_dummy,
+        |b, _i| {
+            let mut bump = bumpalo::Bump::new();
+            b.iter(|| parse_messy_json_arena_dummy_obj(&prepared_dummy, &mut bump))
+        },
+    );
     group.bench_with_input(
         criterion::BenchmarkId::new("deser_messy_json_raw", "dummy_obj"),
         &DUMMY_OBJ,
@@ -31,7 +31,7 @@ fn parse_serde_value_optional_obj(input: &str) -> Value {
     serde_json::from_str(input).unwrap()
 }
 
-fn parse_messy_json_raw_optional_obj(input: &str) -> MessyJsonValueRaw {
+fn parse_messy_json_raw_optional_obj(input: &str) -> MessyJsonValueRaw<'_> {
     serde_json::from_str(input).unwrap()
 }
 
@@ -83,6 +83,19 @@ fn parse_messy_json_optional_obj(schema: &MessyJson) {
         .unwrap();
 }
 
+#[cfg(feature = "bumpalo")]
+fn parse_messy_json_arena_optional_obj(schema: &MessyJson, bump: &mut bumpalo::Bump) {
+    {
+        let mut deserializer = serde_json::Deserializer::from_str(OPTIONAL_OBJ);
+        let _parsed: MessyJsonArenaValue = schema
+            .builder(MessyJsonSettings::default())
+            .in_arena(bump)
+            .deserialize(&mut deserializer)
+            .unwrap();
+    }
+    bump.reset();
+}
+
 pub fn criterion_benchmark(c: &mut Criterion) {
     let prepared_optional = gen_messy_json_schema_optional_obj();
 
@@ -103,6 +116,15 @@ pub fn criterion_benchmark(c: &mut Criterion) {
         &prepared_optional,
         |b, _i| b.iter(|| parse_messy_json_optional_obj(&prepared_optional)),
     );
+    #[cfg(feature = "bumpalo")]
+    group.bench_with_input(
+        criterion::BenchmarkId::new("deser_messy_json_arena", "optional_obj"),
+        &prepared_optional,
+        |b, _i| {
+            let mut bump = bumpalo::Bump::new();
+            b.iter(|| parse_messy_json_arena_optional_obj(&prepared_optional, &mut bump))
+        },
+    );
     group.bench_with_input(
         criterion::BenchmarkId::new("deser_messy_json_raw", "optional_obj"),
         &OPTIONAL_OBJ,
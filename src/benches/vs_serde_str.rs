@@ -13,7 +13,7 @@ struct SimpleStruct<'a> {
     hello: Cow<'a, str>,
 }
 
-fn parse_serde(input: &str) -> SimpleStruct {
+fn parse_serde(input: &str) -> SimpleStruct<'_> {
     serde_json::from_str(input).unwrap()
 }
 
@@ -21,7 +21,7 @@ fn parse_serde_value(input: &str) -> Value {
     serde_json::from_str(input).unwrap()
 }
 
-fn parse_messy_json_raw(input: &str) -> MessyJsonValueRaw {
+fn parse_messy_json_raw(input: &str) -> MessyJsonValueRaw<'_> {
     serde_json::from_str(input).unwrap()
 }
 
@@ -47,6 +47,19 @@ fn parse_messy_json(schema: &MessyJson, input: &str) {
         .unwrap();
 }
 
+#[cfg(feature = "bumpalo")]
+fn parse_messy_json_arena(schema: &MessyJson, input: &str, bump: &mut bumpalo::Bump) {
+    {
+        let mut deserializer = serde_json::Deserializer::from_str(input);
+        let _parsed = schema
+            .builder(MessyJsonSettings::default())
+            .in_arena(bump)
+            .deserialize(&mut deserializer)
+            .unwrap();
+    }
+    bump.reset();
+}
+
 pub fn criterion_benchmark(c: &mut Criterion) {
     let mut group = c.benchmark_group("Simple object");
 
@@ -69,6 +82,16 @@ pub fn criterion_benchmark(c: &mut Criterion) {
             b.iter(|| parse_messy_json(&prepared, i))
         },
     );
+    #[cfg(feature = "bumpalo")]
+    group.bench_with_input(
+        criterion::BenchmarkId::new("deser_messy_json_arena", "simple_obj"),
+        &SIMPLE_OBJ,
+        |b, i| {
+            let prepared = gen_messy_json_schema();
+            let mut bump = bumpalo::Bump::new();
+            b.iter(|| parse_messy_json_arena(&prepared, i, &mut bump))
+        },
+    );
     group.bench_with_input(
         criterion::BenchmarkId::new("deser_messy_json_raw", "simple_obj"),
         &SIMPLE_OBJ,
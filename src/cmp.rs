@@ -0,0 +1,254 @@
+use super::*;
+use serde_json::{Map, Value};
+
+/// ## Settings used to compare a [MessyJsonValue](MessyJsonValue) with a [serde_json::Value](serde_json::Value)
+#[derive(Clone, Debug, PartialEq, Eq, Copy)]
+pub struct MessyJsonCmpSettings {
+    /// If `true`, an [absent](MessyJsonNullType::Absent) key is equal to a key missing from the
+    /// [serde_json::Value](serde_json::Value) object, and is not equal to an explicit `null`.
+    ///
+    /// If `false`, an [absent](MessyJsonNullType::Absent) key is compared as if it was `null`.
+    pub absent_eq_missing: bool,
+}
+
+impl Default for MessyJsonCmpSettings {
+    fn default() -> Self {
+        MessyJsonCmpSettings {
+            absent_eq_missing: true,
+        }
+    }
+}
+
+impl MessyJsonCmpSettings {
+    pub fn absent_eq_missing(&self) -> bool {
+        self.absent_eq_missing
+    }
+}
+
+/// ## Reason of a [MessyJsonMismatch](MessyJsonMismatch)
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum MessyJsonMismatchReason {
+    /// The key is only present in the [MessyJsonValue](MessyJsonValue)
+    OnlyInMessy,
+    /// The key is only present in the [serde_json::Value](serde_json::Value)
+    OnlyInJson,
+    /// The arrays have different lengths
+    Length { messy: usize, json: usize },
+    /// The values are of different types or are different
+    Different,
+}
+
+/// ## Point of disagreement between a [MessyJsonValue](MessyJsonValue) and a [serde_json::Value](serde_json::Value)
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct MessyJsonMismatch {
+    /// The [JSON Pointer](https://tools.ietf.org/html/rfc6901) to the mismatching value
+    pub path: String,
+    /// Why the values are mismatching
+    pub reason: MessyJsonMismatchReason,
+}
+
+enum PathSegment<'a> {
+    Key(&'a str),
+    Index(usize),
+}
+
+struct Comparator<'a, F> {
+    settings: MessyJsonCmpSettings,
+    path: Vec<PathSegment<'a>>,
+    /// Called on every mismatch, returning `false` to stop the comparison
+    report: F,
+}
+
+impl<'a, F> Comparator<'a, F>
+where
+    F: FnMut(MessyJsonMismatch) -> bool,
+{
+    fn new(settings: MessyJsonCmpSettings, report: F) -> Self {
+        Comparator {
+            settings,
+            path: Vec::new(),
+            report,
+        }
+    }
+
+    /// Report a mismatch at the current path, returning `false` if the comparison should stop
+    fn mismatch(&mut self, reason: MessyJsonMismatchReason) -> bool {
+        let mut path = String::new();
+        for segment in self.path.iter() {
+            match segment {
+                PathSegment::Key(key) => pointer::push_token(&mut path, key),
+                PathSegment::Index(idx) => pointer::push_token(&mut path, &idx.to_string()),
+            }
+        }
+        (self.report)(MessyJsonMismatch { path, reason })
+    }
+
+    /// Compare both values, returning `false` if the comparison should stop
+    fn value(&mut self, messy: &'a MessyJsonValue, json: &'a Value) -> bool {
+        match (messy, json) {
+            (MessyJsonValue::Array(mj_arr), Value::Array(v_arr)) => self.array(mj_arr, v_arr),
+            (MessyJsonValue::Obj(mj_obj), Value::Object(v_obj)) => self.object(mj_obj, v_obj),
+            _ if scalar_eq(messy, json) => true,
+            _ => self.mismatch(MessyJsonMismatchReason::Different),
+        }
+    }
+
+    fn array(&mut self, messy: &'a MessyJsonArrayValue, json: &'a [Value]) -> bool {
+        if messy.len() != json.len() {
+            return self.mismatch(MessyJsonMismatchReason::Length {
+                messy: messy.len(),
+                json: json.len(),
+            });
+        }
+        for (idx, (mj_val, v_val)) in messy.iter().zip(json.iter()).enumerate() {
+            self.path.push(PathSegment::Index(idx));
+            let cont = self.value(mj_val, v_val);
+            self.path.pop();
+            if !cont {
+                return false;
+            }
+        }
+        true
+    }
+
+    fn object(&mut self, messy: &'a MessyJsonObjectValue, json: &'a Map<String, Value>) -> bool {
+        for (key, mj_val) in messy.iter() {
+            self.path.push(PathSegment::Key(key.as_str()));
+            let absent = matches!(mj_val, MessyJsonValue::Null(MessyJsonNullType::Absent, _));
+            let cont = match (
+                json.get(key.as_str()),
+                absent && self.settings.absent_eq_missing(),
+            ) {
+                (None, true) => true,
+                (None, false) => self.mismatch(MessyJsonMismatchReason::OnlyInMessy),
+                (Some(_), true) => self.mismatch(MessyJsonMismatchReason::OnlyInJson),
+                (Some(v_val), false) => self.value(mj_val, v_val),
+            };
+            self.path.pop();
+            if !cont {
+                return false;
+            }
+        }
+        for key in json.keys().filter(|k| !messy.contains_key(k.as_str())) {
+            self.path.push(PathSegment::Key(key.as_str()));
+            let cont = self.mismatch(MessyJsonMismatchReason::OnlyInJson);
+            self.path.pop();
+            if !cont {
+                return false;
+            }
+        }
+        true
+    }
+}
+
+/// Compare two numbers, a JSON number being equal only if it represents exactly the same integer
+fn number_eq(messy: u128, json: &serde_json::Number) -> bool {
+    match (json.as_u64(), json.as_f64()) {
+        (Some(x), _) => messy == x as u128,
+        (None, Some(x)) if json.is_f64() => {
+            x >= 0.0 && x.fract() == 0.0 && x as u128 == messy && messy as f64 == x
+        }
+        _ => false,
+    }
+}
+
+/// Compare two scalars. Objects and arrays are never equal.
+fn scalar_eq(messy: &MessyJsonValue, json: &Value) -> bool {
+    match (messy, json) {
+        (MessyJsonValue::Bool(mj_bool), Value::Bool(v_bool)) => mj_bool == v_bool,
+        (MessyJsonValue::Number(mj_number), Value::Number(v_number)) => {
+            number_eq(*mj_number, v_number)
+        }
+        (MessyJsonValue::String(mj_str), Value::String(v_str)) => mj_str == v_str,
+        #[cfg(feature = "uuid")]
+        (MessyJsonValue::Uuid(mj_uuid), Value::String(v_str)) => {
+            feat_uuid::Uuid::parse_str(v_str).is_ok_and(|v_uuid| **mj_uuid == v_uuid)
+        }
+        (MessyJsonValue::Null(_, _), Value::Null) => true,
+        _ => false,
+    }
+}
+
+/// Compare two [MessyJsonValue](MessyJsonValue) as their JSON representation would be compared.
+///
+/// The expected schemas of `null` values are ignored, and [absent](MessyJsonNullType::Absent) keys are considered missing.
+pub(crate) fn value_eq(left: &MessyJsonValue, right: &MessyJsonValue) -> bool {
+    match (left, right) {
+        (MessyJsonValue::Array(l_arr), MessyJsonValue::Array(r_arr)) => {
+            l_arr.len() == r_arr.len()
+                && l_arr.iter().zip(r_arr.iter()).all(|(l, r)| value_eq(l, r))
+        }
+        (MessyJsonValue::Obj(l_obj), MessyJsonValue::Obj(r_obj)) => {
+            let present = |(_, v): &(&ArcStr, &MessyJsonValue)| !v.is_absent();
+            l_obj.iter().filter(present).count() == r_obj.iter().filter(present).count()
+                && l_obj.iter().filter(present).all(|(k, l)| {
+                    r_obj
+                        .get(k.as_str())
+                        .is_some_and(|r| !r.is_absent() && value_eq(l, r))
+                })
+        }
+        (MessyJsonValue::Null(_, _), MessyJsonValue::Null(_, _)) => true,
+        _ => left == right,
+    }
+}
+
+impl<'a> MessyJsonValue<'a> {
+    /// Compare this value with a [serde_json::Value](serde_json::Value) using the provided settings.
+    ///
+    /// Objects are equal if they have the same set of keys, and every value is equal.
+    /// Outside of objects, [absent](MessyJsonNullType::Absent) values are compared as if they were `null`.
+    pub fn eq_json(&self, other: &Value, settings: MessyJsonCmpSettings) -> bool {
+        Comparator::new(settings, |_| false).value(self, other)
+    }
+
+    /// List every point where this value and a [serde_json::Value](serde_json::Value) disagree.
+    ///
+    /// The list is empty if [eq_json](MessyJsonValue::eq_json) would return `true`.
+    pub fn diff_json(
+        &self,
+        other: &Value,
+        settings: MessyJsonCmpSettings,
+    ) -> Vec<MessyJsonMismatch> {
+        let mut res = Vec::new();
+        Comparator::new(settings, |x| {
+            res.push(x);
+            true
+        })
+        .value(self, other);
+        res
+    }
+}
+
+impl<'a> PartialEq<Value> for MessyJsonObjectValue<'a> {
+    fn eq(&self, other: &Value) -> bool {
+        match other {
+            Value::Object(v_obj) => {
+                Comparator::new(MessyJsonCmpSettings::default(), |_| false).object(self, v_obj)
+            }
+            _ => false,
+        }
+    }
+}
+
+impl<'a> PartialEq<Value> for MessyJsonArrayValue<'a> {
+    fn eq(&self, other: &Value) -> bool {
+        match other {
+            Value::Array(v_arr) => {
+                Comparator::new(MessyJsonCmpSettings::default(), |_| false).array(self, v_arr)
+            }
+            _ => false,
+        }
+    }
+}
+
+impl<'a> PartialEq<Value> for MessyJsonValue<'a> {
+    fn eq(&self, other: &Value) -> bool {
+        self.eq_json(other, MessyJsonCmpSettings::default())
+    }
+}
+
+impl<'a> PartialEq<MessyJsonValue<'a>> for Value {
+    fn eq(&self, other: &MessyJsonValue<'a>) -> bool {
+        other.eq(self)
+    }
+}
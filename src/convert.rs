@@ -0,0 +1,131 @@
+//! Conversions between [MessyJsonValue](MessyJsonValue), [MessyJsonValueRaw](MessyJsonValueRaw)
+//! and [serde_json::Value](serde_json::Value).
+use super::*;
+use serde_json::Value;
+use std::convert::TryFrom;
+
+/// Convert a number to a [serde_json::Number](serde_json::Number)
+fn number_to_json(x: u128) -> Result<serde_json::Number, MessyJsonError> {
+    u64::try_from(x)
+        .map(serde_json::Number::from)
+        .map_err(|_| MessyJsonError::NumberOverflow(x))
+}
+
+/// Convert a [serde_json::Number](serde_json::Number) the way [MessyJsonValueRawVisitor](MessyJsonValueRawVisitor) would
+fn number_from_json<'a>(x: &serde_json::Number) -> MessyJsonValueRaw<'a> {
+    match (x.as_u64(), x.as_i64()) {
+        (Some(x), _) => MessyJsonValueRaw::from(x),
+        (None, Some(x)) => MessyJsonValueRaw::from(x),
+        (None, None) => MessyJsonValueRaw::from(x.as_f64().unwrap_or_default()),
+    }
+}
+
+impl<'a> From<MessyJsonValue<'a>> for MessyJsonValueRaw<'a> {
+    fn from(val: MessyJsonValue<'a>) -> Self {
+        match val {
+            MessyJsonValue::Array(arr) => MessyJsonValueRaw::Array(
+                arr.take()
+                    .into_iter()
+                    .map(MessyJsonValueRaw::from)
+                    .collect(),
+            ),
+            MessyJsonValue::Bool(x) => MessyJsonValueRaw::Bool(x),
+            MessyJsonValue::Number(x) => MessyJsonValueRaw::Number(x),
+            MessyJsonValue::Obj(obj) => MessyJsonValueRaw::Obj(
+                obj.take()
+                    .into_iter()
+                    .filter(|(_, v)| {
+                        !matches!(v, MessyJsonValue::Null(MessyJsonNullType::Absent, _))
+                    })
+                    .map(|(k, v)| (Cow::Owned(k.to_string()), MessyJsonValueRaw::from(v)))
+                    .collect(),
+            ),
+            MessyJsonValue::String(x) => MessyJsonValueRaw::String(x),
+            #[cfg(feature = "uuid")]
+            MessyJsonValue::Uuid(x) => MessyJsonValueRaw::String(Cow::Owned(x.to_string())),
+            MessyJsonValue::Null(_, _) => MessyJsonValueRaw::Null,
+        }
+    }
+}
+
+impl<'a> TryFrom<MessyJsonValueRaw<'a>> for Value {
+    type Error = MessyJsonError;
+
+    fn try_from(val: MessyJsonValueRaw<'a>) -> Result<Self, Self::Error> {
+        Ok(match val {
+            MessyJsonValueRaw::Array(arr) => Value::Array(
+                arr.into_iter()
+                    .map(Value::try_from)
+                    .collect::<Result<_, _>>()?,
+            ),
+            MessyJsonValueRaw::Bool(x) => Value::Bool(x),
+            MessyJsonValueRaw::Number(x) => Value::Number(number_to_json(x)?),
+            MessyJsonValueRaw::Obj(obj) => Value::Object(
+                obj.into_iter()
+                    .map(|(k, v)| Ok((k.into_owned(), Value::try_from(v)?)))
+                    .collect::<Result<_, MessyJsonError>>()?,
+            ),
+            MessyJsonValueRaw::String(x) => Value::String(x.into_owned()),
+            MessyJsonValueRaw::Null => Value::Null,
+        })
+    }
+}
+
+impl<'a> TryFrom<MessyJsonValue<'a>> for Value {
+    type Error = MessyJsonError;
+
+    fn try_from(val: MessyJsonValue<'a>) -> Result<Self, Self::Error> {
+        Value::try_from(MessyJsonValueRaw::from(val))
+    }
+}
+
+impl<'a> From<&'a Value> for MessyJsonValueRaw<'a> {
+    fn from(val: &'a Value) -> Self {
+        match val {
+            Value::Array(arr) => {
+                MessyJsonValueRaw::Array(arr.iter().map(MessyJsonValueRaw::from).collect())
+            }
+            Value::Bool(x) => MessyJsonValueRaw::Bool(*x),
+            Value::Number(x) => number_from_json(x),
+            Value::Object(obj) => MessyJsonValueRaw::Obj(
+                obj.iter()
+                    .map(|(k, v)| (Cow::Borrowed(k.as_str()), MessyJsonValueRaw::from(v)))
+                    .collect(),
+            ),
+            Value::String(x) => MessyJsonValueRaw::String(Cow::Borrowed(x.as_str())),
+            Value::Null => MessyJsonValueRaw::Null,
+        }
+    }
+}
+
+impl From<Value> for MessyJsonValueRaw<'static> {
+    fn from(val: Value) -> Self {
+        match val {
+            Value::Array(arr) => {
+                MessyJsonValueRaw::Array(arr.into_iter().map(MessyJsonValueRaw::from).collect())
+            }
+            Value::Bool(x) => MessyJsonValueRaw::Bool(x),
+            Value::Number(x) => number_from_json(&x),
+            Value::Object(obj) => MessyJsonValueRaw::Obj(
+                obj.into_iter()
+                    .map(|(k, v)| (Cow::Owned(k), MessyJsonValueRaw::from(v)))
+                    .collect(),
+            ),
+            Value::String(x) => MessyJsonValueRaw::String(Cow::Owned(x)),
+            Value::Null => MessyJsonValueRaw::Null,
+        }
+    }
+}
+
+impl MessyJson {
+    /// Validate a [serde_json::Value](serde_json::Value) against this schema.
+    ///
+    /// Every string of the resulting value borrows from `value`.
+    pub fn validate_value<'a>(
+        &self,
+        value: &'a Value,
+        settings: MessyJsonSettings,
+    ) -> Result<MessyJsonValue<'a>, serde::de::value::Error> {
+        self.validate_raw_owned(MessyJsonValueRaw::from(value), settings)
+    }
+}
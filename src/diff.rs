@@ -0,0 +1,241 @@
+use super::*;
+use crate::cmp::value_eq;
+
+/// ## A change between two [MessyJsonValue](MessyJsonValue)
+///
+/// Every path is a [JSON Pointer](https://tools.ietf.org/html/rfc6901).
+///
+/// [Absent](MessyJsonNullType::Absent) keys are considered as missing, so going from
+/// [absent](MessyJsonNullType::Absent) to `null` is an addition while going from a value to `null` is a modification.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum MessyJsonChange<'v, 'a> {
+    /// The value is only present in the new version
+    Added {
+        path: String,
+        value: &'v MessyJsonValue<'a>,
+    },
+    /// The value is only present in the old version
+    Removed {
+        path: String,
+        old: &'v MessyJsonValue<'a>,
+    },
+    /// The value is present in both versions but is different
+    Modified {
+        path: String,
+        old: &'v MessyJsonValue<'a>,
+        new: &'v MessyJsonValue<'a>,
+    },
+}
+
+impl<'v, 'a> MessyJsonChange<'v, 'a> {
+    /// The path of the changed value
+    pub fn path(&self) -> &str {
+        match self {
+            MessyJsonChange::Added { path, .. } => path,
+            MessyJsonChange::Removed { path, .. } => path,
+            MessyJsonChange::Modified { path, .. } => path,
+        }
+    }
+}
+
+/// ## Structural difference between two [MessyJsonValue](MessyJsonValue)
+///
+/// Created using [MessyJson::diff](MessyJson::diff).
+#[derive(Clone, Debug)]
+pub struct MessyJsonDiff<'v, 'a> {
+    schema: &'v MessyJson,
+    new: &'v MessyJsonValue<'a>,
+    changes: Vec<MessyJsonChange<'v, 'a>>,
+}
+
+/// Get a value of an object, considering absent values as missing
+fn present<'v, 'a>(obj: &'v MessyJsonObjectValue<'a>, key: &str) -> Option<&'v MessyJsonValue<'a>> {
+    obj.get(key).filter(|x| !x.is_absent())
+}
+
+/// Accumulate the changes between two values
+struct Differ<'v, 'a> {
+    path: String,
+    changes: Vec<MessyJsonChange<'v, 'a>>,
+}
+
+impl<'v, 'a> Differ<'v, 'a> {
+    fn with_token<F: FnOnce(&mut Self)>(&mut self, token: &str, f: F) {
+        let len = self.path.len();
+        pointer::push_token(&mut self.path, token);
+        f(self);
+        self.path.truncate(len);
+    }
+
+    fn obj(&mut self, old: &'v MessyJsonObjectValue<'a>, new: &'v MessyJsonObjectValue<'a>) {
+        let keys = itertools::merge_join_by(
+            crate::map::sorted_keys(old),
+            crate::map::sorted_keys(new),
+            |k1, k2| k1.cmp(k2),
+        )
+        .map(|x| x.reduce(|k, _| k));
+        for key in keys {
+            self.with_token(key, |this| match (present(old, key), present(new, key)) {
+                (Some(old), Some(new)) => this.value(old, new),
+                (Some(old), None) => this.changes.push(MessyJsonChange::Removed {
+                    path: this.path.clone(),
+                    old,
+                }),
+                (None, Some(value)) => this.changes.push(MessyJsonChange::Added {
+                    path: this.path.clone(),
+                    value,
+                }),
+                (None, None) => (),
+            });
+        }
+    }
+
+    fn array(&mut self, old: &'v MessyJsonArrayValue<'a>, new: &'v MessyJsonArrayValue<'a>) {
+        for (idx, (old, new)) in old.iter().zip(new.iter()).enumerate() {
+            self.with_token(&idx.to_string(), |this| this.value(old, new));
+        }
+        // Removals are listed from the end so the changes can be applied sequentially
+        for (idx, old) in old.iter().enumerate().skip(new.len()).rev() {
+            self.with_token(&idx.to_string(), |this| {
+                this.changes.push(MessyJsonChange::Removed {
+                    path: this.path.clone(),
+                    old,
+                })
+            });
+        }
+        for (idx, value) in new.iter().enumerate().skip(old.len()) {
+            self.with_token(&idx.to_string(), |this| {
+                this.changes.push(MessyJsonChange::Added {
+                    path: this.path.clone(),
+                    value,
+                })
+            });
+        }
+    }
+
+    fn value(&mut self, old: &'v MessyJsonValue<'a>, new: &'v MessyJsonValue<'a>) {
+        match (old, new) {
+            (MessyJsonValue::Obj(old), MessyJsonValue::Obj(new)) => self.obj(old, new),
+            (MessyJsonValue::Array(old), MessyJsonValue::Array(new)) => self.array(old, new),
+            _ if !value_eq(old, new) => self.changes.push(MessyJsonChange::Modified {
+                path: self.path.clone(),
+                old,
+                new,
+            }),
+            _ => (),
+        }
+    }
+}
+
+impl MessyJson {
+    /// Compute the changes needed to go from the `old` value to the `new` one.
+    ///
+    /// Both values are expected to match this schema, which is used to type the `null`s of
+    /// the rendered [JSON Merge Patch](MessyJsonDiff::to_merge_patch).
+    pub fn diff<'v, 'a>(
+        &'v self,
+        old: &'v MessyJsonValue<'a>,
+        new: &'v MessyJsonValue<'a>,
+    ) -> MessyJsonDiff<'v, 'a> {
+        let mut differ = Differ {
+            path: String::new(),
+            changes: Vec::new(),
+        };
+        differ.value(old, new);
+        MessyJsonDiff {
+            schema: self,
+            new,
+            changes: differ.changes,
+        }
+    }
+}
+
+impl<'v, 'a> MessyJsonDiff<'v, 'a> {
+    /// The list of changes, objects' keys being sorted
+    pub fn changes(&self) -> &[MessyJsonChange<'v, 'a>] {
+        &self.changes
+    }
+
+    /// `true` if both values are equal
+    pub fn is_empty(&self) -> bool {
+        self.changes.is_empty()
+    }
+
+    /// Render the changes as a [JSON Patch](https://tools.ietf.org/html/rfc6902)
+    pub fn to_json_patch(&self) -> Vec<MessyJsonPatchOperation<'a>> {
+        self.changes
+            .iter()
+            .map(|change| match change {
+                MessyJsonChange::Added { path, value } => MessyJsonPatchOperation::Add {
+                    path: path.clone(),
+                    value: (*value).clone(),
+                },
+                MessyJsonChange::Removed { path, .. } => {
+                    MessyJsonPatchOperation::Remove { path: path.clone() }
+                }
+                MessyJsonChange::Modified { path, new, .. } => MessyJsonPatchOperation::Replace {
+                    path: path.clone(),
+                    value: (*new).clone(),
+                },
+            })
+            .collect()
+    }
+
+    /// Render the changes as a [JSON Merge Patch](https://tools.ietf.org/html/rfc7396)
+    ///
+    /// As JSON Merge Patch can't describe changes inside arrays, every modified array is
+    /// replaced as a whole. Setting a key to `null` can't be distinguished from removing it.
+    pub fn to_merge_patch(&self) -> MessyJsonValue<'a> {
+        let mut res = MessyJsonValue::Obj(MessyJsonObjectValue::default());
+        for change in self.changes.iter() {
+            let tokens: Vec<Cow<str>> = pointer::split(change.path())
+                .map(Iterator::collect)
+                .unwrap_or_default();
+            // Look for the first array on the way to the changed value
+            let mut new_node = Some(self.new);
+            let mut cut = tokens.len();
+            for (idx, token) in tokens.iter().enumerate() {
+                match new_node {
+                    Some(MessyJsonValue::Array(_)) => {
+                        cut = idx;
+                        break;
+                    }
+                    Some(MessyJsonValue::Obj(x)) => new_node = x.get(token.as_ref()),
+                    _ => new_node = None,
+                }
+            }
+            let value = match (cut < tokens.len(), change) {
+                (true, _) => new_node.cloned().unwrap_or_else(|| self.new.clone()),
+                (false, MessyJsonChange::Removed { path, .. }) => MessyJsonValue::Null(
+                    MessyJsonNullType::Null,
+                    MessyJsonExpected::Root(
+                        self.schema.pointer(path).unwrap_or(self.schema).clone(),
+                    ),
+                ),
+                (false, MessyJsonChange::Added { value, .. }) => (*value).clone(),
+                (false, MessyJsonChange::Modified { new, .. }) => (*new).clone(),
+            };
+            insert_merge_patch(&mut res, &tokens[..cut], value);
+        }
+        res
+    }
+}
+
+/// Insert a value in a merge patch, creating the intermediate objects
+fn insert_merge_patch<'a>(
+    patch: &mut MessyJsonValue<'a>,
+    tokens: &[Cow<str>],
+    value: MessyJsonValue<'a>,
+) {
+    match tokens.split_first() {
+        None => *patch = value,
+        Some((token, rest)) => {
+            if let MessyJsonValue::Obj(obj) = patch {
+                let child = obj
+                    .entry(ArcStr::from(token.as_ref()))
+                    .or_insert_with(|| MessyJsonValue::Obj(MessyJsonObjectValue::default()));
+                insert_merge_patch(child, rest, value);
+            }
+        }
+    }
+}
@@ -0,0 +1,66 @@
+/// ## Error of a `messy_json` operation
+///
+/// Errors raised while validating a document against a schema are reported using
+/// `serde`'s error types. This error covers the other operations of this crate.
+#[derive(Clone, Debug, PartialEq)]
+#[non_exhaustive]
+pub enum MessyJsonError {
+    /// A number doesn't fit in a [serde_json::Number](serde_json::Number)
+    NumberOverflow(u128),
+    /// The result of the operation doesn't match the schema
+    Validation(serde::de::value::Error),
+    /// The operation expected an object
+    NotAnObject,
+    /// The [JSON Pointer](https://tools.ietf.org/html/rfc6901) is malformed or doesn't point to an existing value
+    InvalidPointer(String),
+    /// The JSON Patch document is malformed
+    InvalidPatch(String),
+    /// A JSON Patch `test` operation failed
+    TestFailed(String),
+    /// The schemas can't be merged because they disagree on the type of a value
+    SchemaConflict(String),
+    /// The query string is malformed
+    InvalidQuery(String),
+    /// The alias is already used by another property
+    AliasConflict(String),
+    /// The key matches several properties of the object
+    AmbiguousKey(String),
+    /// The object has no property with this name
+    UnknownProperty(String),
+}
+
+impl std::fmt::Display for MessyJsonError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            MessyJsonError::NumberOverflow(x) => write!(
+                f,
+                "The number `{}` is too big to be represented in a JSON number",
+                x
+            ),
+            MessyJsonError::Validation(x) => write!(f, "{}", x),
+            MessyJsonError::NotAnObject => write!(f, "The value is not an object"),
+            MessyJsonError::InvalidPointer(x) => write!(f, "The pointer `{}` is invalid", x),
+            MessyJsonError::InvalidPatch(x) => write!(f, "Invalid patch: {}", x),
+            MessyJsonError::TestFailed(x) => write!(f, "The test of `{}` failed", x),
+            MessyJsonError::SchemaConflict(x) => {
+                write!(f, "The schemas have incompatible types at `{}`", x)
+            }
+            MessyJsonError::InvalidQuery(x) => write!(f, "Invalid query string: {}", x),
+            MessyJsonError::AliasConflict(x) => {
+                write!(f, "The alias `{}` is already used by another property", x)
+            }
+            MessyJsonError::AmbiguousKey(x) => {
+                write!(f, "The key `{}` matches several properties", x)
+            }
+            MessyJsonError::UnknownProperty(x) => write!(f, "The property `{}` doesn't exist", x),
+        }
+    }
+}
+
+impl std::error::Error for MessyJsonError {}
+
+impl From<serde::de::value::Error> for MessyJsonError {
+    fn from(err: serde::de::value::Error) -> Self {
+        MessyJsonError::Validation(err)
+    }
+}
@@ -115,31 +115,74 @@ use std::collections::BTreeMap;
 use std::ops::Deref;
 use std::sync::Arc;
 
+use limits::{MessyJsonIgnoredAny, MessyJsonLimitsState};
+
+mod access;
+#[cfg(feature = "bumpalo")]
+mod arena;
 mod array;
+#[cfg(feature = "tokio")]
+mod async_read;
+mod cmp;
+mod convert;
+mod diff;
+mod error;
+mod limits;
+mod lines;
+mod map;
+mod merge_patch;
 mod number;
 mod object;
+mod overrides;
+mod owned;
+mod patch;
+mod pointer;
+mod projection;
+mod query;
 mod raw_value;
 mod scalar;
 mod schema;
 mod schema_visitor;
+mod serialize;
 mod settings;
+mod sink;
+mod stream;
+mod transform;
+mod validate;
 mod value;
+mod value_mut;
 
 #[cfg(test)]
 mod tests;
 
+#[cfg(feature = "bumpalo")]
+pub use arena::{MessyJsonArenaBuilder, MessyJsonArenaObject, MessyJsonArenaValue};
 pub use array::MessyJsonArray;
+pub use cmp::{MessyJsonCmpSettings, MessyJsonMismatch, MessyJsonMismatchReason};
+pub use diff::{MessyJsonChange, MessyJsonDiff};
+pub use error::MessyJsonError;
+pub use limits::{MessyJsonLimitExceeded, MessyJsonLimits};
+pub use lines::{MessyJsonLines, MessyJsonLinesError, MessyJsonStrLines};
+pub use map::MessyJsonMap;
 pub use number::{MessyJsonNumberType, MessyJsonNumeric};
 #[cfg(test)]
 pub use object::gen_key;
-pub use object::{KeyType, MessyJsonObject, MessyJsonObjectInner};
+pub use object::{KeyType, MessyJsonFieldAccess, MessyJsonObject, MessyJsonObjectInner};
+pub use overrides::MessyJsonSettingsOverrides;
+pub use patch::MessyJsonPatchOperation;
+pub use projection::{MessyJsonProjection, MessyJsonProjectionMode};
 pub use raw_value::{MessyJsonValueRaw, MessyJsonValueRawVisitor};
 pub use scalar::MessyJsonScalar;
 pub use schema::{
     MessyJson, MessyJsonBuilder, MessyJsonExpected, MessyJsonInner, MessyJsonObjectBuilder,
 };
-pub use settings::MessyJsonSettings;
+pub use serialize::MessyJsonSerializer;
+pub use settings::{
+    MessyJsonDirection, MessyJsonDuplicateKeys, MessyJsonKeyMatching, MessyJsonPartialSettings,
+    MessyJsonSettings,
+};
 pub use value::{
     MessyJsonArrayValue, MessyJsonNullType, MessyJsonObjectValue, MessyJsonValue,
     MessyJsonValueContainer,
 };
+pub use value_mut::MessyJsonValueMut;
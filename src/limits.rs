@@ -0,0 +1,332 @@
+use super::*;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+/// ## Resource limits of a document
+///
+/// Bound the resources spent on a document, including its free-form parts parsed as
+/// [MessyJsonValueRaw](MessyJsonValueRaw). The deserialization fails as soon as a limit is
+/// exceeded, with a [MessyJsonLimitExceeded](MessyJsonLimitExceeded) message that
+/// [MessyJsonLimitExceeded::from_error](MessyJsonLimitExceeded::from_error) recovers.
+///
+/// The values skipped without being kept, like the unknown keys or the keys left out of a
+/// projection, are bounded as well. The limits also apply when validating an already parsed
+/// [MessyJsonValueRaw](MessyJsonValueRaw), and so to the query strings and the JSON Patch
+/// documents.
+///
+/// The size of the document is only bounded by the functions reading it themselves, like
+/// [parse_lines](MessyJson::parse_lines) or
+/// [from_query](MessyJsonValueRaw::from_query). When creating the deserializer yourself,
+/// bound the size of the input beforehand (i.e. using [take](std::io::Read::take)): the
+/// strings are only checked once `serde_json` has read them entirely.
+///
+/// A limit set to `None` is disabled.
+#[derive(Clone, Debug, PartialEq, Eq, Copy, Hash, Default)]
+pub struct MessyJsonLimits {
+    /// Maximum nesting depth of the arrays and objects, the root being at depth 1
+    pub max_depth: Option<usize>,
+    /// Maximum number of keys of an object
+    pub max_keys: Option<usize>,
+    /// Maximum number of items of an array
+    pub max_array_length: Option<usize>,
+    /// Maximum length of a string or a key, in bytes
+    pub max_string_length: Option<usize>,
+    /// Maximum number of values of the whole document
+    pub max_nodes: Option<usize>,
+    /// Maximum size of the document, in bytes
+    pub max_document_size: Option<usize>,
+}
+
+/// ## A [limit](MessyJsonLimits) exceeded while deserializing
+///
+/// Each variant holds the value of the limit. As `serde` errors only carry a message, use
+/// [from_error](MessyJsonLimitExceeded::from_error) to get it back from a deserialization
+/// error.
+#[derive(Clone, Debug, PartialEq, Eq, Copy, Hash)]
+#[non_exhaustive]
+pub enum MessyJsonLimitExceeded {
+    Depth(usize),
+    Keys(usize),
+    ArrayLength(usize),
+    StringLength(usize),
+    Nodes(usize),
+    DocumentSize(usize),
+}
+
+/// Prefix of the message of every [MessyJsonLimitExceeded](MessyJsonLimitExceeded)
+const LIMIT_EXCEEDED: &str = "Limit exceeded: ";
+
+impl MessyJsonLimitExceeded {
+    /// Find the limit exceeded by a deserialization error, if any.
+    ///
+    /// `serde`'s errors only keep the message of the original error, so the limit is recovered
+    /// from the message. This works with any error displaying it, such as `serde_json::Error`.
+    pub fn from_error<E: std::fmt::Display>(err: &E) -> Option<Self> {
+        const KINDS: [fn(usize) -> MessyJsonLimitExceeded; 6] = [
+            MessyJsonLimitExceeded::Depth,
+            MessyJsonLimitExceeded::Keys,
+            MessyJsonLimitExceeded::ArrayLength,
+            MessyJsonLimitExceeded::StringLength,
+            MessyJsonLimitExceeded::Nodes,
+            MessyJsonLimitExceeded::DocumentSize,
+        ];
+        let msg = err.to_string();
+        let msg = &msg[msg.find(LIMIT_EXCEEDED)? + LIMIT_EXCEEDED.len()..];
+        KINDS.iter().find_map(|kind| {
+            let (prefix, _, suffix) = kind(0).parts();
+            let rest = msg.strip_prefix(prefix)?;
+            let end = rest
+                .find(|c: char| !c.is_ascii_digit())
+                .unwrap_or(rest.len());
+            let limit = rest[..end].parse().ok()?;
+            rest[end..].starts_with(suffix).then(|| kind(limit))
+        })
+    }
+
+    /// Split the message into the text before the limit, the limit and the text after it
+    fn parts(&self) -> (&'static str, usize, &'static str) {
+        match *self {
+            MessyJsonLimitExceeded::Depth(x) => ("the maximum depth is ", x, ""),
+            MessyJsonLimitExceeded::Keys(x) => ("an object can't have more than ", x, " keys"),
+            MessyJsonLimitExceeded::ArrayLength(x) => {
+                ("an array can't have more than ", x, " items")
+            }
+            MessyJsonLimitExceeded::StringLength(x) => {
+                ("a string can't be longer than ", x, " bytes")
+            }
+            MessyJsonLimitExceeded::Nodes(x) => {
+                ("the document can't have more than ", x, " values")
+            }
+            MessyJsonLimitExceeded::DocumentSize(x) => {
+                ("the document can't be longer than ", x, " bytes")
+            }
+        }
+    }
+}
+
+impl std::fmt::Display for MessyJsonLimitExceeded {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let (prefix, limit, suffix) = self.parts();
+        write!(f, "{}{}{}{}", LIMIT_EXCEEDED, prefix, limit, suffix)
+    }
+}
+
+impl std::error::Error for MessyJsonLimitExceeded {}
+
+/// Fail if `value` is above `limit`
+#[inline]
+fn check<E>(
+    limit: Option<usize>,
+    value: usize,
+    err: fn(usize) -> MessyJsonLimitExceeded,
+) -> Result<(), E>
+where
+    E: serde::de::Error,
+{
+    match limit {
+        Some(limit) if value > limit => Err(E::custom(err(limit))),
+        _ => Ok(()),
+    }
+}
+
+impl MessyJsonLimits {
+    #[inline]
+    pub(crate) fn check_keys<E: serde::de::Error>(&self, count: usize) -> Result<(), E> {
+        check(self.max_keys, count, MessyJsonLimitExceeded::Keys)
+    }
+
+    #[inline]
+    pub(crate) fn check_array_length<E: serde::de::Error>(&self, len: usize) -> Result<(), E> {
+        check(
+            self.max_array_length,
+            len,
+            MessyJsonLimitExceeded::ArrayLength,
+        )
+    }
+
+    #[inline]
+    pub(crate) fn check_string_length<E: serde::de::Error>(&self, len: usize) -> Result<(), E> {
+        check(
+            self.max_string_length,
+            len,
+            MessyJsonLimitExceeded::StringLength,
+        )
+    }
+
+    /// Get the error of a document longer than `max_document_size`, if any
+    #[inline]
+    pub(crate) fn document_size_exceeded(&self, len: usize) -> Option<MessyJsonLimitExceeded> {
+        match self.max_document_size {
+            Some(limit) if len > limit => Some(MessyJsonLimitExceeded::DocumentSize(limit)),
+            _ => None,
+        }
+    }
+}
+
+/// Usage of the [limits](MessyJsonLimits) spanning over multiple values of a document
+#[derive(Clone, Debug, Default)]
+pub(crate) struct MessyJsonLimitsState {
+    /// Number of arrays and objects containing the current value
+    depth: usize,
+    /// Number of values of the document, shared by all of its values
+    nodes: Option<Arc<AtomicUsize>>,
+}
+
+impl PartialEq for MessyJsonLimitsState {
+    fn eq(&self, other: &Self) -> bool {
+        self.depth == other.depth
+            && self.nodes.as_ref().map(|x| x.load(Ordering::Relaxed))
+                == other.nodes.as_ref().map(|x| x.load(Ordering::Relaxed))
+    }
+}
+
+impl Eq for MessyJsonLimitsState {}
+
+impl MessyJsonLimitsState {
+    /// Start counting the values of the document if the limits require it
+    #[inline]
+    pub(crate) fn track(mut self, limits: &MessyJsonLimits) -> Self {
+        if limits.max_nodes.is_some() && self.nodes.is_none() {
+            self.nodes = Some(Arc::default());
+        }
+        self
+    }
+
+    /// Get the state of a value nested in the current one
+    #[inline]
+    pub(crate) fn nested(&self) -> Self {
+        MessyJsonLimitsState {
+            depth: self.depth + 1,
+            nodes: self.nodes.clone(),
+        }
+    }
+
+    /// Account for a new value of the document
+    #[inline]
+    pub(crate) fn count_node<E: serde::de::Error>(
+        &self,
+        limits: &MessyJsonLimits,
+    ) -> Result<(), E> {
+        match (limits.max_nodes, &self.nodes) {
+            (Some(_), Some(nodes)) => check(
+                limits.max_nodes,
+                nodes.fetch_add(1, Ordering::Relaxed) + 1,
+                MessyJsonLimitExceeded::Nodes,
+            ),
+            _ => Ok(()),
+        }
+    }
+
+    /// Check the depth of an array or an object starting at the current value
+    #[inline]
+    pub(crate) fn check_depth<E: serde::de::Error>(
+        &self,
+        limits: &MessyJsonLimits,
+    ) -> Result<(), E> {
+        check(
+            limits.max_depth,
+            self.depth + 1,
+            MessyJsonLimitExceeded::Depth,
+        )
+    }
+}
+
+/// Seed skipping a value like [IgnoredAny](serde::de::IgnoredAny), while enforcing the
+/// [limits](MessyJsonLimits)
+#[derive(Clone, Debug)]
+pub(crate) struct MessyJsonIgnoredAny<'a> {
+    limits: &'a MessyJsonLimits,
+    state: MessyJsonLimitsState,
+}
+
+impl<'a> MessyJsonIgnoredAny<'a> {
+    /// Create the seed of a value, `state` being the usage of the limits at this value
+    #[inline]
+    pub(crate) fn new(limits: &'a MessyJsonLimits, state: MessyJsonLimitsState) -> Self {
+        MessyJsonIgnoredAny { limits, state }
+    }
+
+    #[inline]
+    fn nested(&self) -> Self {
+        MessyJsonIgnoredAny::new(self.limits, self.state.nested())
+    }
+}
+
+impl<'de, 'a> DeserializeSeed<'de> for MessyJsonIgnoredAny<'a> {
+    type Value = ();
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        if *self.limits == MessyJsonLimits::default() {
+            return deserializer
+                .deserialize_ignored_any(serde::de::IgnoredAny)
+                .map(|_| ());
+        }
+        self.state.count_node(self.limits)?;
+        deserializer.deserialize_any(self)
+    }
+}
+
+impl<'de, 'a> Visitor<'de> for MessyJsonIgnoredAny<'a> {
+    type Value = ();
+
+    fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(formatter, "any valid json")
+    }
+
+    fn visit_bool<E>(self, _v: bool) -> Result<Self::Value, E> {
+        Ok(())
+    }
+
+    fn visit_i64<E>(self, _v: i64) -> Result<Self::Value, E> {
+        Ok(())
+    }
+
+    fn visit_u64<E>(self, _v: u64) -> Result<Self::Value, E> {
+        Ok(())
+    }
+
+    fn visit_f64<E>(self, _v: f64) -> Result<Self::Value, E> {
+        Ok(())
+    }
+
+    fn visit_str<E>(self, v: &str) -> Result<Self::Value, E>
+    where
+        E: serde::de::Error,
+    {
+        self.limits.check_string_length(v.len())
+    }
+
+    fn visit_unit<E>(self) -> Result<Self::Value, E> {
+        Ok(())
+    }
+
+    fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+    where
+        A: MapAccess<'de>,
+    {
+        self.state.check_depth(self.limits)?;
+        let mut count: usize = 0;
+        while let Some(key) = map.next_key_seed(crate::schema_visitor::MessyJsonKeySeed)? {
+            count += 1;
+            self.limits.check_keys(count)?;
+            self.limits.check_string_length(key.len())?;
+            map.next_value_seed(self.nested())?;
+        }
+        Ok(())
+    }
+
+    fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+    where
+        A: SeqAccess<'de>,
+    {
+        self.state.check_depth(self.limits)?;
+        let mut count: usize = 0;
+        while seq.next_element_seed(self.nested())?.is_some() {
+            count += 1;
+            self.limits.check_array_length(count)?;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,267 @@
+use super::*;
+use std::io::{BufRead, Read};
+
+/// ## Error of a line of a [JSON Lines](https://jsonlines.org) document
+///
+/// The lines are numbered from 1.
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum MessyJsonLinesError {
+    /// The line couldn't be read
+    Io { line: usize, error: std::io::Error },
+    /// The line isn't valid JSON or doesn't match the schema
+    Invalid {
+        line: usize,
+        error: serde_json::Error,
+    },
+}
+
+impl MessyJsonLinesError {
+    /// Get the number of the line in error
+    pub fn line(&self) -> usize {
+        match self {
+            MessyJsonLinesError::Io { line, .. } | MessyJsonLinesError::Invalid { line, .. } => {
+                *line
+            }
+        }
+    }
+}
+
+impl std::fmt::Display for MessyJsonLinesError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            MessyJsonLinesError::Io { line, error } => {
+                write!(f, "Failed to read line {}: {}", line, error)
+            }
+            MessyJsonLinesError::Invalid { line, error } => write!(f, "Line {}: {}", line, error),
+        }
+    }
+}
+
+impl std::error::Error for MessyJsonLinesError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            MessyJsonLinesError::Io { error, .. } => Some(error),
+            MessyJsonLinesError::Invalid { error, .. } => Some(error),
+        }
+    }
+}
+
+/// State shared by the line iterators
+struct LinesState<'s> {
+    schema: &'s MessyJson,
+    settings: MessyJsonSettings,
+    line: usize,
+    skip_invalid: bool,
+    done: bool,
+}
+
+impl<'s> LinesState<'s> {
+    fn new(schema: &'s MessyJson, settings: MessyJsonSettings) -> Self {
+        LinesState {
+            schema,
+            settings,
+            line: 0,
+            skip_invalid: false,
+            done: false,
+        }
+    }
+
+    /// Validate the current line, stopping the iteration on error unless the invalid lines
+    /// are skipped
+    fn parse<'a, R>(&mut self, read: R) -> Result<MessyJsonValueContainer<'a>, MessyJsonLinesError>
+    where
+        R: serde_json::de::Read<'a>,
+    {
+        let mut deserializer = serde_json::Deserializer::new(read);
+        let res = self
+            .schema
+            .builder(self.settings)
+            .deserialize(&mut deserializer)
+            .and_then(|x| deserializer.end().map(|_| x));
+        res.map_err(|error| self.invalid(error))
+    }
+
+    /// Report the current line as invalid, stopping the iteration unless the invalid lines
+    /// are skipped
+    fn invalid(&mut self, error: serde_json::Error) -> MessyJsonLinesError {
+        self.done = !self.skip_invalid;
+        MessyJsonLinesError::Invalid {
+            line: self.line,
+            error,
+        }
+    }
+
+    /// Check the length of a line against
+    /// [max_document_size](MessyJsonLimits::max_document_size)
+    fn check_size(&mut self, len: usize) -> Result<(), MessyJsonLinesError> {
+        match self.settings.limits().document_size_exceeded(len) {
+            Some(exceeded) => Err(self.invalid(serde::de::Error::custom(exceeded))),
+            None => Ok(()),
+        }
+    }
+}
+
+/// Consume the rest of the current line
+fn skip_line<R: BufRead>(reader: &mut R) -> std::io::Result<()> {
+    loop {
+        let buf = reader.fill_buf()?;
+        match buf.iter().position(|x| *x == b'\n') {
+            _ if buf.is_empty() => return Ok(()),
+            Some(idx) => {
+                reader.consume(idx + 1);
+                return Ok(());
+            }
+            None => {
+                let len = buf.len();
+                reader.consume(len);
+            }
+        }
+    }
+}
+
+/// ## Iterator over the values of a [JSON Lines](https://jsonlines.org) reader
+///
+/// Each line is validated against the schema, a line that isn't valid UTF-8 being invalid.
+/// Blank lines are ignored.
+///
+/// The iteration stops after the first error, unless the invalid lines are
+/// [skipped](MessyJsonLines::skip_invalid). The
+/// [max_document_size](MessyJsonLimits::max_document_size) limit applies to each line, a
+/// longer line being refused without being buffered.
+pub struct MessyJsonLines<'s, R> {
+    reader: R,
+    buf: Vec<u8>,
+    state: LinesState<'s>,
+}
+
+impl<'s, R: BufRead> MessyJsonLines<'s, R> {
+    /// Go on after the lines that are invalid instead of stopping, their errors being
+    /// yielded as they occur.
+    ///
+    /// Failing to read the input still stops the iteration.
+    pub fn skip_invalid(mut self) -> Self {
+        self.state.skip_invalid = true;
+        self
+    }
+}
+
+impl<'s, R: BufRead> Iterator for MessyJsonLines<'s, R> {
+    type Item = Result<MessyJsonValueContainer<'static>, MessyJsonLinesError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while !self.state.done {
+            self.buf.clear();
+            self.state.line += 1;
+            // Don't read more than the size of a line, plus its line feed
+            let read = match self.state.settings.limits().max_document_size {
+                Some(limit) => (&mut self.reader)
+                    .take(limit as u64 + 1)
+                    .read_until(b'\n', &mut self.buf),
+                None => self.reader.read_until(b'\n', &mut self.buf),
+            };
+            match read {
+                Ok(0) => self.state.done = true,
+                Ok(len) => {
+                    let complete = self.buf.last() == Some(&b'\n');
+                    if let Err(err) = self.state.check_size(len - complete as usize) {
+                        if !complete {
+                            if let Err(error) = skip_line(&mut self.reader) {
+                                self.state.done = true;
+                                return Some(Err(MessyJsonLinesError::Io {
+                                    line: self.state.line,
+                                    error,
+                                }));
+                            }
+                        }
+                        return Some(Err(err));
+                    }
+                    if self.buf.iter().all(u8::is_ascii_whitespace) {
+                        continue;
+                    }
+                    let read = serde_json::de::IoRead::new(self.buf.as_slice());
+                    return Some(self.state.parse(read));
+                }
+                Err(error) => {
+                    self.state.done = true;
+                    return Some(Err(MessyJsonLinesError::Io {
+                        line: self.state.line,
+                        error,
+                    }));
+                }
+            }
+        }
+        None
+    }
+}
+
+/// ## Iterator over the values of a [JSON Lines](https://jsonlines.org) string
+///
+/// Like [MessyJsonLines](MessyJsonLines), the strings of the values borrowing from the input.
+pub struct MessyJsonStrLines<'s, 'a> {
+    lines: std::str::Lines<'a>,
+    state: LinesState<'s>,
+}
+
+impl<'s, 'a> MessyJsonStrLines<'s, 'a> {
+    /// Go on after the lines that are invalid instead of stopping, their errors being
+    /// yielded as they occur
+    pub fn skip_invalid(mut self) -> Self {
+        self.state.skip_invalid = true;
+        self
+    }
+}
+
+impl<'s, 'a> Iterator for MessyJsonStrLines<'s, 'a> {
+    type Item = Result<MessyJsonValueContainer<'a>, MessyJsonLinesError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while !self.state.done {
+            let line = match self.lines.next() {
+                Some(x) => x,
+                None => {
+                    self.state.done = true;
+                    break;
+                }
+            };
+            self.state.line += 1;
+            if let Err(err) = self.state.check_size(line.len()) {
+                return Some(Err(err));
+            }
+            if line.trim().is_empty() {
+                continue;
+            }
+            return Some(self.state.parse(serde_json::de::StrRead::new(line)));
+        }
+        None
+    }
+}
+
+impl MessyJson {
+    /// Iterate over the values of a [JSON Lines](https://jsonlines.org) reader,
+    /// validating each line against this schema
+    pub fn parse_lines<R: BufRead>(
+        &self,
+        reader: R,
+        settings: MessyJsonSettings,
+    ) -> MessyJsonLines<'_, R> {
+        MessyJsonLines {
+            reader,
+            buf: Vec::new(),
+            state: LinesState::new(self, settings),
+        }
+    }
+
+    /// Iterate over the values of a [JSON Lines](https://jsonlines.org) string,
+    /// validating each line against this schema
+    pub fn parse_lines_str<'a>(
+        &self,
+        input: &'a str,
+        settings: MessyJsonSettings,
+    ) -> MessyJsonStrLines<'_, 'a> {
+        MessyJsonStrLines {
+            lines: input.lines(),
+            state: LinesState::new(self, settings),
+        }
+    }
+}
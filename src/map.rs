@@ -0,0 +1,53 @@
+#[cfg(feature = "preserve_order")]
+use itertools::Itertools;
+use std::borrow::Borrow;
+use std::hash::Hash;
+
+/// ## Map of the keys of a JSON object
+///
+/// The keys are sorted alphabetically.
+///
+/// With the `preserve_order` feature, this is an [IndexMap](indexmap::IndexMap)
+/// keeping the keys in the order of the document.
+#[cfg(not(feature = "preserve_order"))]
+pub type MessyJsonMap<K, V> = std::collections::BTreeMap<K, V>;
+
+/// ## Map of the keys of a JSON object
+///
+/// The keys are kept in the order of the document.
+#[cfg(feature = "preserve_order")]
+pub type MessyJsonMap<K, V> = indexmap::IndexMap<K, V>;
+
+/// Remove a key, keeping the order of the other keys
+#[cfg(not(feature = "preserve_order"))]
+#[inline]
+pub(crate) fn remove<K, V>(map: &mut MessyJsonMap<K, V>, key: &str) -> Option<V>
+where
+    K: Borrow<str> + Ord + Hash,
+{
+    map.remove(key)
+}
+
+/// Remove a key, keeping the order of the other keys
+#[cfg(feature = "preserve_order")]
+#[inline]
+pub(crate) fn remove<K, V>(map: &mut MessyJsonMap<K, V>, key: &str) -> Option<V>
+where
+    K: Borrow<str> + Ord + Hash,
+{
+    map.shift_remove(key)
+}
+
+/// Iterate over the keys of a map in alphabetical order
+#[cfg(not(feature = "preserve_order"))]
+#[inline]
+pub(crate) fn sorted_keys<K: Ord + Hash, V>(map: &MessyJsonMap<K, V>) -> impl Iterator<Item = &K> {
+    map.keys()
+}
+
+/// Iterate over the keys of a map in alphabetical order
+#[cfg(feature = "preserve_order")]
+#[inline]
+pub(crate) fn sorted_keys<K: Ord + Hash, V>(map: &MessyJsonMap<K, V>) -> impl Iterator<Item = &K> {
+    map.keys().sorted()
+}
@@ -0,0 +1,70 @@
+use super::*;
+use crate::validate::validate_value;
+
+/// Merge an object patch into an object target
+fn merge_obj<'a>(target: &mut MessyJsonObjectValue<'a>, patch: &MessyJsonObjectValue<'a>) {
+    for (key, patch_val) in patch.iter() {
+        match patch_val {
+            MessyJsonValue::Null(MessyJsonNullType::Absent, _) => (),
+            MessyJsonValue::Null(MessyJsonNullType::Null, expected) => {
+                if let Some(target_val) = target.get_mut(key.as_str()) {
+                    *target_val = MessyJsonValue::Null(MessyJsonNullType::Absent, expected.clone());
+                }
+            }
+            _ => match target.get_mut(key.as_str()) {
+                Some(target_val) => target_val.apply_merge_patch(patch_val),
+                None => {
+                    let mut target_val = MessyJsonValue::Obj(MessyJsonObjectValue::default());
+                    target_val.apply_merge_patch(patch_val);
+                    target.insert(key.clone(), target_val);
+                }
+            },
+        }
+    }
+}
+
+impl<'a> MessyJsonValue<'a> {
+    /// Apply a [JSON Merge Patch](https://tools.ietf.org/html/rfc7396) to this value,
+    /// without checking the result against any schema.
+    ///
+    /// - An [absent](MessyJsonNullType::Absent) value in the patch leaves the target untouched.
+    /// - An explicit `null` in the patch deletes the target key, marking it as [absent](MessyJsonNullType::Absent).
+    /// - Any other value replaces the target, objects being merged recursively.
+    ///
+    /// See [MessyJsonValueMut::apply_merge_patch](MessyJsonValueMut::apply_merge_patch) for a schema-checked version.
+    pub fn apply_merge_patch(&mut self, patch: &MessyJsonValue<'a>) {
+        match patch {
+            MessyJsonValue::Null(MessyJsonNullType::Absent, _) => (),
+            MessyJsonValue::Obj(patch_obj) => {
+                if !matches!(self, MessyJsonValue::Obj(_)) {
+                    *self = MessyJsonValue::Obj(MessyJsonObjectValue::default());
+                }
+                if let MessyJsonValue::Obj(target_obj) = self {
+                    merge_obj(target_obj, patch_obj);
+                }
+            }
+            _ => *self = patch.clone(),
+        }
+    }
+}
+
+impl<'v, 'a> MessyJsonValueMut<'v, 'a> {
+    /// Apply a [JSON Merge Patch](https://tools.ietf.org/html/rfc7396) to this value.
+    ///
+    /// The patch is checked against the schema as if every field was optional, refusing
+    /// to set a mandatory key to `null` if [preserve_mandatory](MessyJsonSettings::preserve_mandatory) is set.
+    /// The result is then checked against the schema, leaving the value untouched in case of error.
+    ///
+    /// See [MessyJsonValue::apply_merge_patch](MessyJsonValue::apply_merge_patch) for the merging rules.
+    pub fn apply_merge_patch(&mut self, patch: &MessyJsonValue<'a>) -> Result<(), MessyJsonError> {
+        let patch_settings = MessyJsonSettings {
+            all_optional: true,
+            ..*self.settings()
+        };
+        validate_value(self.schema(), &patch_settings, patch)?;
+        let mut res = self.value().clone();
+        res.apply_merge_patch(patch);
+        self.set(res)?;
+        Ok(())
+    }
+}
@@ -16,18 +16,13 @@ impl MessyJsonNumeric {
 }
 
 /// ## JSON Number type schema
-#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, Default)]
 pub enum MessyJsonNumberType {
+    #[default]
     U64,
     U128,
 }
 
-impl Default for MessyJsonNumberType {
-    fn default() -> Self {
-        MessyJsonNumberType::U64
-    }
-}
-
 impl MessyJsonNumeric {
     /// ## Get the type of number
     #[inline]
@@ -40,4 +35,9 @@ impl MessyJsonNumeric {
     pub fn optional(&self) -> bool {
         self.optional
     }
+
+    #[inline]
+    pub(crate) fn set_optional(&mut self, optional: bool) {
+        self.optional = optional;
+    }
 }
@@ -15,6 +15,12 @@ impl MessyJsonObject {
     pub fn builder(&self, settings: MessyJsonSettings) -> MessyJsonObjectBuilder {
         MessyJsonObjectBuilder::new(self, settings)
     }
+
+    /// Get a mutable reference to the inner object, cloning it if it's shared
+    #[inline]
+    pub(crate) fn inner_mut(&mut self) -> &mut MessyJsonObjectInner {
+        Arc::make_mut(&mut self.0)
+    }
 }
 
 impl std::ops::Deref for MessyJsonObject {
@@ -31,6 +37,32 @@ impl From<MessyJsonObjectInner> for MessyJsonObject {
     }
 }
 
+/// ## Access restriction of an object's property
+///
+/// Checked according to the [direction](MessyJsonDirection) of the [settings](MessyJsonSettings).
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, Default)]
+pub enum MessyJsonFieldAccess {
+    /// The property can be both sent and received
+    #[default]
+    ReadWrite,
+    /// The property can't be sent as input (i.e. an `id`)
+    ReadOnly,
+    /// The property is never part of the output (i.e. a `password`)
+    WriteOnly,
+}
+
+impl MessyJsonFieldAccess {
+    /// Check if the property is allowed in a direction
+    #[inline]
+    pub fn allows(&self, direction: MessyJsonDirection) -> bool {
+        !matches!(
+            (self, direction),
+            (MessyJsonFieldAccess::ReadOnly, MessyJsonDirection::Input)
+                | (MessyJsonFieldAccess::WriteOnly, MessyJsonDirection::Output)
+        )
+    }
+}
+
 /// ## JSON Object schema value
 ///
 /// Describe a JSON Object at runtime
@@ -38,15 +70,131 @@ impl From<MessyJsonObjectInner> for MessyJsonObject {
 pub struct MessyJsonObjectInner {
     optional: bool,
     properties: BTreeMap<KeyType, MessyJson>,
+    access: BTreeMap<KeyType, MessyJsonFieldAccess>,
+    aliases: BTreeMap<KeyType, KeyType>,
+    /// The properties with their position, to find both with a single lookup
+    index: BTreeMap<KeyType, (usize, MessyJson)>,
 }
 
 impl MessyJsonObjectInner {
     /// Create a new [MessyJsonObject](MessyJsonObject)
     pub fn new(properties: BTreeMap<KeyType, MessyJson>, optional: bool) -> Self {
         MessyJsonObjectInner {
-            properties: properties.into_iter().collect(),
+            index: index(&properties),
+            properties,
             optional,
+            access: BTreeMap::new(),
+            aliases: BTreeMap::new(),
+        }
+    }
+
+    /// Add an alias to a property, the value being stored under the name of the property.
+    ///
+    /// Fails with [UnknownProperty](MessyJsonError::UnknownProperty) if the property doesn't
+    /// exist, and with [AliasConflict](MessyJsonError::AliasConflict) if the alias is already
+    /// the name of a property or the alias of another property.
+    pub fn with_alias(mut self, key: &str, alias: &str) -> Result<Self, MessyJsonError> {
+        if !self.properties.contains_key(key) {
+            return Err(MessyJsonError::UnknownProperty(key.to_string()));
+        }
+        let conflict = self.properties.contains_key(alias)
+            || matches!(self.aliases.get(alias), Some(property) if property.as_str() != key);
+        if conflict {
+            return Err(MessyJsonError::AliasConflict(alias.to_string()));
         }
+        self.aliases.insert(ArcStr::from(alias), ArcStr::from(key));
+        Ok(self)
+    }
+
+    /// Get a reference to the aliases of the properties, mapped to the name of their property
+    #[inline]
+    pub fn aliases(&self) -> &BTreeMap<KeyType, KeyType> {
+        &self.aliases
+    }
+
+    /// Find the property matching a key, either by its name or by one of its aliases.
+    ///
+    /// An exact match is always preferred. Otherwise, with a relaxed [matching](MessyJsonKeyMatching),
+    /// a key matching several properties is refused with [AmbiguousKey](MessyJsonError::AmbiguousKey).
+    pub fn resolve_key(
+        &self,
+        key: &str,
+        matching: MessyJsonKeyMatching,
+    ) -> Result<Option<(&KeyType, &MessyJson)>, MessyJsonError> {
+        Ok(self
+            .resolve_property(key, matching)?
+            .map(|(_, key, schema)| (key, schema)))
+    }
+
+    /// Like [resolve_key](MessyJsonObjectInner::resolve_key), also returning the position of
+    /// the property among the [properties](MessyJsonObjectInner::properties)
+    pub(crate) fn resolve_property(
+        &self,
+        key: &str,
+        matching: MessyJsonKeyMatching,
+    ) -> Result<Option<(usize, &KeyType, &MessyJson)>, MessyJsonError> {
+        if let Some(res) = self.property(key) {
+            return Ok(Some(res));
+        }
+        if let Some(property) = self.aliases.get(key) {
+            return Ok(self.property(property));
+        }
+        if matching == MessyJsonKeyMatching::Exact {
+            return Ok(None);
+        }
+        let mut candidates = self
+            .properties
+            .keys()
+            .filter(|property| matching.matches(property, key))
+            .chain(
+                self.aliases
+                    .iter()
+                    .filter(|(alias, _)| matching.matches(alias, key))
+                    .map(|(_, property)| property),
+            );
+        let property = match candidates.next() {
+            Some(x) => x,
+            None => return Ok(None),
+        };
+        if candidates.any(|other| other != property) {
+            return Err(MessyJsonError::AmbiguousKey(key.to_string()));
+        }
+        Ok(self.property(property))
+    }
+
+    /// Get a property with its position among the [properties](MessyJsonObjectInner::properties)
+    #[inline]
+    fn property(&self, key: &str) -> Option<(usize, &KeyType, &MessyJson)> {
+        self.index
+            .get_key_value(key)
+            .map(|(key, (position, schema))| (*position, key, schema))
+    }
+
+    /// Restrict the access of a property
+    pub fn with_access(mut self, key: &str, access: MessyJsonFieldAccess) -> Self {
+        match access {
+            MessyJsonFieldAccess::ReadWrite => self.access.remove(key),
+            _ => self.access.insert(ArcStr::from(key), access),
+        };
+        self
+    }
+
+    /// Get the access restriction of a property
+    #[inline]
+    pub fn access(&self, key: &str) -> MessyJsonFieldAccess {
+        self.access.get(key).copied().unwrap_or_default()
+    }
+
+    /// Get a reference to the access restrictions of the properties
+    #[inline]
+    pub fn access_restrictions(&self) -> &BTreeMap<KeyType, MessyJsonFieldAccess> {
+        &self.access
+    }
+
+    /// Check if a property is allowed in the direction of the settings
+    #[inline]
+    pub(crate) fn allows(&self, key: &str, settings: &MessyJsonSettings) -> bool {
+        self.access.is_empty() || self.access(key).allows(settings.direction())
     }
 
     /// Get a reference to the [MessyJsonObject](MessyJsonObject)'s properties
@@ -66,6 +214,52 @@ impl MessyJsonObjectInner {
     pub fn optional(&self) -> bool {
         self.optional
     }
+
+    /// Get a mutable reference to the properties, [prune](MessyJsonObjectInner::prune) being
+    /// required once they're modified
+    #[inline]
+    pub(crate) fn properties_mut(&mut self) -> &mut BTreeMap<KeyType, MessyJson> {
+        &mut self.properties
+    }
+
+    #[inline]
+    pub(crate) fn access_mut(&mut self) -> &mut BTreeMap<KeyType, MessyJsonFieldAccess> {
+        &mut self.access
+    }
+
+    #[inline]
+    pub(crate) fn aliases_mut(&mut self) -> &mut BTreeMap<KeyType, KeyType> {
+        &mut self.aliases
+    }
+
+    /// Drop the default access restrictions, as well as the access restrictions and aliases
+    /// of the properties that no longer exist and the aliases shadowed by a property.
+    ///
+    /// Must be called once the properties are modified, to update their index.
+    pub(crate) fn prune(&mut self) {
+        let properties = &self.properties;
+        self.index = index(properties);
+        self.access.retain(|key, access| {
+            *access != MessyJsonFieldAccess::ReadWrite && properties.contains_key(key)
+        });
+        self.aliases.retain(|alias, property| {
+            properties.contains_key(property) && !properties.contains_key(alias)
+        });
+    }
+
+    #[inline]
+    pub(crate) fn set_optional(&mut self, optional: bool) {
+        self.optional = optional;
+    }
+}
+
+/// Index the properties with their position in the map
+fn index(properties: &BTreeMap<KeyType, MessyJson>) -> BTreeMap<KeyType, (usize, MessyJson)> {
+    properties
+        .iter()
+        .enumerate()
+        .map(|(position, (key, schema))| (key.clone(), (position, schema.clone())))
+        .collect()
 }
 
 #[cfg(test)]
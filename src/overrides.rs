@@ -0,0 +1,74 @@
+use super::*;
+
+#[derive(Clone, Debug, PartialEq, Eq, Default)]
+struct MessyJsonSettingsOverridesInner {
+    settings: Option<MessyJsonPartialSettings>,
+    fields: BTreeMap<ArcStr, MessyJsonSettingsOverrides>,
+}
+
+/// ## Tree of [MessyJsonSettings](MessyJsonSettings) overrides
+///
+/// Each node of the tree can override some of the settings used to deserialize the matching
+/// value, the other ones being inherited from the parent value. The settings of a node are
+/// inherited by every nested value, until they are overridden again.
+///
+/// Arrays are transparent: the overrides of an array apply to each of its items.
+#[derive(Clone, Debug, PartialEq, Eq, Default)]
+pub struct MessyJsonSettingsOverrides(Arc<MessyJsonSettingsOverridesInner>);
+
+impl MessyJsonSettingsOverrides {
+    /// Create an empty tree of overrides
+    pub fn new() -> Self {
+        MessyJsonSettingsOverrides::default()
+    }
+
+    /// Override the settings of this node
+    pub fn with_settings(mut self, settings: MessyJsonPartialSettings) -> Self {
+        Arc::make_mut(&mut self.0).settings = Some(settings);
+        self
+    }
+
+    /// Set the overrides of a field
+    pub fn with_field(mut self, key: &str, nested: MessyJsonSettingsOverrides) -> Self {
+        Arc::make_mut(&mut self.0)
+            .fields
+            .insert(ArcStr::from(key), nested);
+        self
+    }
+
+    /// Override the settings of the value at a [JSON Pointer](https://tools.ietf.org/html/rfc6901)
+    ///
+    /// As arrays are transparent, the pointer shouldn't contain array indexes.
+    pub fn with_pointer(
+        self,
+        pointer: &str,
+        settings: MessyJsonPartialSettings,
+    ) -> Result<Self, MessyJsonError> {
+        let tokens: Vec<Cow<str>> = crate::pointer::split(pointer)
+            .ok_or_else(|| MessyJsonError::InvalidPointer(pointer.to_string()))?
+            .collect();
+        Ok(self.with_path(&tokens, settings))
+    }
+
+    fn with_path(self, tokens: &[Cow<str>], settings: MessyJsonPartialSettings) -> Self {
+        match tokens.split_first() {
+            None => self.with_settings(settings),
+            Some((token, rest)) => {
+                let nested = self.get(token).cloned().unwrap_or_default();
+                self.with_field(token, nested.with_path(rest, settings))
+            }
+        }
+    }
+
+    /// Get the settings of this node, if overridden
+    #[inline]
+    pub fn settings(&self) -> Option<&MessyJsonPartialSettings> {
+        self.0.settings.as_ref()
+    }
+
+    /// Get the overrides of a field
+    #[inline]
+    pub fn get(&self, key: &str) -> Option<&MessyJsonSettingsOverrides> {
+        self.0.fields.get(key)
+    }
+}
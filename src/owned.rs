@@ -0,0 +1,93 @@
+use super::*;
+
+/// Take the ownership of a string
+#[inline]
+fn owned_str(x: Cow<'_, str>) -> Cow<'static, str> {
+    Cow::Owned(x.into_owned())
+}
+
+impl<'a> MessyJsonValue<'a> {
+    /// Convert into a value owning every string, so it can outlive the deserialized data
+    pub fn into_owned(self) -> MessyJsonValue<'static> {
+        match self {
+            MessyJsonValue::Array(x) => MessyJsonValue::Array(x.into_owned()),
+            MessyJsonValue::Bool(x) => MessyJsonValue::Bool(x),
+            MessyJsonValue::Number(x) => MessyJsonValue::Number(x),
+            MessyJsonValue::Obj(x) => MessyJsonValue::Obj(x.into_owned()),
+            MessyJsonValue::String(x) => MessyJsonValue::String(owned_str(x)),
+            #[cfg(feature = "uuid")]
+            MessyJsonValue::Uuid(x) => MessyJsonValue::Uuid(Cow::Owned(x.into_owned())),
+            MessyJsonValue::Null(null_type, expected) => MessyJsonValue::Null(null_type, expected),
+        }
+    }
+
+    /// Clone into a value owning every string
+    #[inline]
+    pub fn to_owned(&self) -> MessyJsonValue<'static> {
+        self.clone().into_owned()
+    }
+}
+
+impl<'a> MessyJsonObjectValue<'a> {
+    /// Convert into an object owning every string
+    pub fn into_owned(self) -> MessyJsonObjectValue<'static> {
+        MessyJsonObjectValue::from(
+            self.take()
+                .into_iter()
+                .map(|(key, value)| (key, value.into_owned()))
+                .collect::<MessyJsonMap<_, _>>(),
+        )
+    }
+}
+
+impl<'a> MessyJsonArrayValue<'a> {
+    /// Convert into an array owning every string
+    pub fn into_owned(self) -> MessyJsonArrayValue<'static> {
+        MessyJsonArrayValue::from(
+            self.take()
+                .into_iter()
+                .map(MessyJsonValue::into_owned)
+                .collect::<Vec<_>>(),
+        )
+    }
+}
+
+impl<'a> MessyJsonValueContainer<'a> {
+    /// Convert into a container owning every string, so it can outlive the deserialized data
+    #[inline]
+    pub fn into_owned(self) -> MessyJsonValueContainer<'static> {
+        MessyJsonValueContainer::new(self.take().into_owned())
+    }
+
+    /// Clone into a container owning every string
+    #[inline]
+    pub fn to_owned(&self) -> MessyJsonValueContainer<'static> {
+        MessyJsonValueContainer::new(self.inner().to_owned())
+    }
+}
+
+impl<'a> MessyJsonValueRaw<'a> {
+    /// Convert into a value owning every string and key, so it can outlive the deserialized data
+    pub fn into_owned(self) -> MessyJsonValueRaw<'static> {
+        match self {
+            MessyJsonValueRaw::Array(x) => {
+                MessyJsonValueRaw::Array(x.into_iter().map(MessyJsonValueRaw::into_owned).collect())
+            }
+            MessyJsonValueRaw::Bool(x) => MessyJsonValueRaw::Bool(x),
+            MessyJsonValueRaw::Number(x) => MessyJsonValueRaw::Number(x),
+            MessyJsonValueRaw::Obj(x) => MessyJsonValueRaw::Obj(
+                x.into_iter()
+                    .map(|(key, value)| (owned_str(key), value.into_owned()))
+                    .collect(),
+            ),
+            MessyJsonValueRaw::String(x) => MessyJsonValueRaw::String(owned_str(x)),
+            MessyJsonValueRaw::Null => MessyJsonValueRaw::Null,
+        }
+    }
+
+    /// Clone into a value owning every string and key
+    #[inline]
+    pub fn to_owned(&self) -> MessyJsonValueRaw<'static> {
+        self.clone().into_owned()
+    }
+}
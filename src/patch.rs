@@ -0,0 +1,306 @@
+use super::*;
+use crate::cmp::value_eq;
+
+/// ## [JSON Patch](https://tools.ietf.org/html/rfc6902) operation
+///
+/// Every path is a [JSON Pointer](https://tools.ietf.org/html/rfc6901).
+/// [Absent](MessyJsonNullType::Absent) values are considered as non-existent.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum MessyJsonPatchOperation<'a> {
+    /// Add a value to an object or insert it into an array
+    Add {
+        path: String,
+        value: MessyJsonValue<'a>,
+    },
+    /// Remove an existing value
+    Remove { path: String },
+    /// Replace an existing value
+    Replace {
+        path: String,
+        value: MessyJsonValue<'a>,
+    },
+    /// Remove a value and add it at another location
+    Move { from: String, path: String },
+    /// Copy a value to another location
+    Copy { from: String, path: String },
+    /// Check that the value at a location is equal to a value
+    Test {
+        path: String,
+        value: MessyJsonValue<'a>,
+    },
+}
+
+/// Split a pointer into its parent tokens and its last token
+fn split_last(path: &str) -> Result<(Vec<Cow<'_, str>>, Cow<'_, str>), MessyJsonError> {
+    let mut tokens: Vec<Cow<str>> = pointer::split(path)
+        .ok_or_else(|| MessyJsonError::InvalidPointer(path.to_string()))?
+        .collect();
+    let last = tokens
+        .pop()
+        .ok_or_else(|| MessyJsonError::InvalidPointer(path.to_string()))?;
+    Ok((tokens, last))
+}
+
+/// Resolve a list of tokens to an existing value
+fn resolve_mut<'v, 'a>(
+    value: &'v mut MessyJsonValue<'a>,
+    tokens: &[Cow<str>],
+    path: &str,
+) -> Result<&'v mut MessyJsonValue<'a>, MessyJsonError> {
+    tokens
+        .iter()
+        .try_fold(value, |target, token| match target {
+            MessyJsonValue::Obj(x) => x.get_mut(token.as_ref()).filter(|x| !x.is_absent()),
+            MessyJsonValue::Array(x) => x.get_mut(pointer::parse_index(token)?),
+            _ => None,
+        })
+        .ok_or_else(|| MessyJsonError::InvalidPointer(path.to_string()))
+}
+
+/// Get an existing value
+fn get<'v, 'a>(
+    value: &'v MessyJsonValue<'a>,
+    path: &str,
+) -> Result<&'v MessyJsonValue<'a>, MessyJsonError> {
+    pointer::split(path)
+        .and_then(|mut tokens| {
+            tokens.try_fold(value, |target, token| match target {
+                MessyJsonValue::Obj(x) => x.get(token.as_ref()).filter(|x| !x.is_absent()),
+                MessyJsonValue::Array(x) => x.get(pointer::parse_index(&token)?),
+                _ => None,
+            })
+        })
+        .ok_or_else(|| MessyJsonError::InvalidPointer(path.to_string()))
+}
+
+fn add<'a>(
+    target: &mut MessyJsonValue<'a>,
+    path: &str,
+    value: MessyJsonValue<'a>,
+) -> Result<(), MessyJsonError> {
+    if path.is_empty() {
+        *target = value;
+        return Ok(());
+    }
+    let (parents, last) = split_last(path)?;
+    match resolve_mut(target, &parents, path)? {
+        MessyJsonValue::Obj(obj) => {
+            obj.insert(ArcStr::from(last.as_ref()), value);
+        }
+        MessyJsonValue::Array(arr) => {
+            let idx = match last.as_ref() {
+                "-" => arr.len(),
+                x => pointer::parse_index(x)
+                    .filter(|idx| *idx <= arr.len())
+                    .ok_or_else(|| MessyJsonError::InvalidPointer(path.to_string()))?,
+            };
+            arr.insert(idx, value);
+        }
+        _ => return Err(MessyJsonError::InvalidPointer(path.to_string())),
+    }
+    Ok(())
+}
+
+/// Remove a value, marking it as [absent](MessyJsonNullType::Absent) if the schema of
+/// its object is known
+fn remove<'a>(
+    target: &mut MessyJsonValue<'a>,
+    path: &str,
+    schema: Option<&MessyJson>,
+) -> Result<MessyJsonValue<'a>, MessyJsonError> {
+    let (parents, last) = split_last(path)?;
+    let val_schema = schema.and_then(|x| x.pointer(path));
+    match resolve_mut(target, &parents, path)? {
+        MessyJsonValue::Obj(obj) => match (obj.get_mut(last.as_ref()), val_schema) {
+            (Some(val), _) if val.is_absent() => {
+                Err(MessyJsonError::InvalidPointer(path.to_string()))
+            }
+            (Some(val), Some(val_schema)) => Ok(std::mem::replace(
+                val,
+                MessyJsonValue::Null(
+                    MessyJsonNullType::Absent,
+                    MessyJsonExpected::Root(val_schema.clone()),
+                ),
+            )),
+            (Some(_), None) => obj
+                .remove(last.as_ref())
+                .ok_or_else(|| MessyJsonError::InvalidPointer(path.to_string())),
+            (None, _) => Err(MessyJsonError::InvalidPointer(path.to_string())),
+        },
+        MessyJsonValue::Array(arr) => match pointer::parse_index(&last) {
+            Some(idx) if idx < arr.len() => Ok(arr.remove(idx)),
+            _ => Err(MessyJsonError::InvalidPointer(path.to_string())),
+        },
+        _ => Err(MessyJsonError::InvalidPointer(path.to_string())),
+    }
+}
+
+/// Apply the operations of a JSON Patch, using the schema of the value if it's known
+fn apply<'a>(
+    target: &mut MessyJsonValue<'a>,
+    operations: &[MessyJsonPatchOperation<'a>],
+    schema: Option<&MessyJson>,
+) -> Result<(), MessyJsonError> {
+    for operation in operations {
+        match operation {
+            MessyJsonPatchOperation::Add { path, value } => add(target, path, value.clone())?,
+            MessyJsonPatchOperation::Remove { path } => {
+                if path.is_empty() {
+                    return Err(MessyJsonError::InvalidPointer(path.clone()));
+                }
+                remove(target, path, schema)?;
+            }
+            MessyJsonPatchOperation::Replace { path, value } => {
+                let tokens: Vec<Cow<str>> = pointer::split(path)
+                    .ok_or_else(|| MessyJsonError::InvalidPointer(path.clone()))?
+                    .collect();
+                *resolve_mut(target, &tokens, path)? = value.clone();
+            }
+            MessyJsonPatchOperation::Move { from, path } => {
+                if path.starts_with(from.as_str()) && path[from.len()..].starts_with('/') {
+                    return Err(MessyJsonError::InvalidPatch(format!(
+                        "Can't move `{}` into one of its children",
+                        from
+                    )));
+                }
+                if from != path {
+                    let value = remove(target, from, schema)?;
+                    add(target, path, value)?;
+                }
+            }
+            MessyJsonPatchOperation::Copy { from, path } => {
+                let value = get(target, from)?.clone();
+                add(target, path, value)?;
+            }
+            MessyJsonPatchOperation::Test { path, value } => {
+                if !value_eq(get(target, path)?, value) {
+                    return Err(MessyJsonError::TestFailed(path.clone()));
+                }
+            }
+        }
+    }
+    Ok(())
+}
+
+impl<'a> MessyJsonValue<'a> {
+    /// Apply a [JSON Patch](https://tools.ietf.org/html/rfc6902) to this value, without
+    /// checking the result against any schema.
+    ///
+    /// The operations are applied in order, stopping at the first error. Without a schema to
+    /// describe them, removed keys are missing from their object rather than
+    /// [absent](MessyJsonNullType::Absent).
+    ///
+    /// See [MessyJsonValueMut::apply_patch](MessyJsonValueMut::apply_patch) for a schema-checked, atomic version.
+    pub fn apply_patch(
+        &mut self,
+        operations: &[MessyJsonPatchOperation<'a>],
+    ) -> Result<(), MessyJsonError> {
+        apply(self, operations, None)
+    }
+}
+
+impl<'v, 'a> MessyJsonValueMut<'v, 'a> {
+    /// Apply a [JSON Patch](https://tools.ietf.org/html/rfc6902) to this value.
+    ///
+    /// The result is checked against the schema. If any operation fails or if the
+    /// result is invalid, the value is left untouched.
+    ///
+    /// Removed keys are kept as [absent](MessyJsonNullType::Absent) values, just like
+    /// [MessyJsonValueMut::remove](MessyJsonValueMut::remove).
+    pub fn apply_patch(
+        &mut self,
+        operations: &[MessyJsonPatchOperation<'a>],
+    ) -> Result<(), MessyJsonError> {
+        let mut res = self.value().clone();
+        apply(&mut res, operations, Some(self.schema()))?;
+        self.set(res)?;
+        Ok(())
+    }
+}
+
+/// Extract a string member of a JSON Patch operation
+fn patch_member(
+    operation: &mut MessyJsonMap<Cow<str>, MessyJsonValueRaw>,
+    key: &str,
+) -> Result<String, MessyJsonError> {
+    match crate::map::remove(operation, key) {
+        Some(MessyJsonValueRaw::String(x)) => Ok(x.into_owned()),
+        _ => Err(MessyJsonError::InvalidPatch(format!(
+            "The member `{}` is missing or isn't a string",
+            key
+        ))),
+    }
+}
+
+impl MessyJson {
+    /// Parse a [JSON Patch](https://tools.ietf.org/html/rfc6902) document.
+    ///
+    /// The values of the `add`, `replace` and `test` operations are validated against the
+    /// schema of the location they're targeting.
+    pub fn parse_patch<'a>(
+        &self,
+        patch: MessyJsonValueRaw<'a>,
+        settings: MessyJsonSettings,
+    ) -> Result<Vec<MessyJsonPatchOperation<'a>>, MessyJsonError> {
+        let operations = match patch {
+            MessyJsonValueRaw::Array(x) => x,
+            _ => {
+                return Err(MessyJsonError::InvalidPatch(
+                    "The patch should be an array".to_string(),
+                ))
+            }
+        };
+        let mut res = Vec::with_capacity(operations.len());
+        for operation in operations {
+            let mut operation = match operation {
+                MessyJsonValueRaw::Obj(x) => x,
+                _ => {
+                    return Err(MessyJsonError::InvalidPatch(
+                        "An operation should be an object".to_string(),
+                    ))
+                }
+            };
+            let op = patch_member(&mut operation, "op")?;
+            let path = patch_member(&mut operation, "path")?;
+            let mut value = || -> Result<MessyJsonValue<'a>, MessyJsonError> {
+                let schema = self
+                    .pointer(&path)
+                    .ok_or_else(|| MessyJsonError::InvalidPointer(path.clone()))?;
+                let value = crate::map::remove(&mut operation, "value").ok_or_else(|| {
+                    MessyJsonError::InvalidPatch("The member `value` is missing".to_string())
+                })?;
+                Ok(schema.validate_raw_owned(value, settings)?)
+            };
+            res.push(match op.as_str() {
+                "add" => MessyJsonPatchOperation::Add {
+                    value: value()?,
+                    path,
+                },
+                "replace" => MessyJsonPatchOperation::Replace {
+                    value: value()?,
+                    path,
+                },
+                "test" => MessyJsonPatchOperation::Test {
+                    value: value()?,
+                    path,
+                },
+                "remove" => MessyJsonPatchOperation::Remove { path },
+                "move" => MessyJsonPatchOperation::Move {
+                    from: patch_member(&mut operation, "from")?,
+                    path,
+                },
+                "copy" => MessyJsonPatchOperation::Copy {
+                    from: patch_member(&mut operation, "from")?,
+                    path,
+                },
+                x => {
+                    return Err(MessyJsonError::InvalidPatch(format!(
+                        "Unknown operation `{}`",
+                        x
+                    )))
+                }
+            });
+        }
+        Ok(res)
+    }
+}
@@ -0,0 +1,40 @@
+//! Helpers to manipulate [JSON Pointers](https://tools.ietf.org/html/rfc6901)
+use std::borrow::Cow;
+
+/// Append a reference token to a JSON Pointer, escaping it.
+pub(crate) fn push_token(pointer: &mut String, token: &str) {
+    pointer.push('/');
+    for c in token.chars() {
+        match c {
+            '~' => pointer.push_str("~0"),
+            '/' => pointer.push_str("~1"),
+            c => pointer.push(c),
+        }
+    }
+}
+
+/// Split a JSON Pointer into its unescaped reference tokens.
+///
+/// Return `None` if the pointer is neither empty nor starting with a `/`.
+pub(crate) fn split(pointer: &str) -> Option<impl Iterator<Item = Cow<'_, str>>> {
+    if !pointer.is_empty() && !pointer.starts_with('/') {
+        return None;
+    }
+    Some(
+        pointer
+            .split('/')
+            .skip(1)
+            .map(|token| match token.contains('~') {
+                true => Cow::Owned(token.replace("~1", "/").replace("~0", "~")),
+                false => Cow::Borrowed(token),
+            }),
+    )
+}
+
+/// Parse an array index reference token, refusing leading zeros
+pub(crate) fn parse_index(token: &str) -> Option<usize> {
+    if token.starts_with('+') || (token.len() > 1 && token.starts_with('0')) {
+        return None;
+    }
+    token.parse().ok()
+}
@@ -0,0 +1,96 @@
+use super::*;
+
+/// ## Set of fields to keep while deserializing
+///
+/// A projection is a tree of keys. A key without any nested key selects the whole value,
+/// a key with nested keys only selects these nested keys.
+///
+/// Arrays are transparent: the projection of an array applies to each of its items.
+///
+/// An empty projection selects everything.
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Default)]
+pub struct MessyJsonProjection(Arc<BTreeMap<ArcStr, MessyJsonProjection>>);
+
+/// ## How the fields left out of a [MessyJsonProjection](MessyJsonProjection) are handled
+#[derive(Clone, Debug, PartialEq, Eq, Copy, Hash, Default)]
+pub enum MessyJsonProjectionMode {
+    /// The fields are skipped without being validated nor allocated
+    #[default]
+    Skip,
+    /// The fields are validated against the schema, then dropped
+    Validate,
+}
+
+impl MessyJsonProjection {
+    /// Create a projection selecting everything
+    pub fn new() -> Self {
+        MessyJsonProjection::default()
+    }
+
+    /// Create a projection from a list of [JSON Pointers](https://tools.ietf.org/html/rfc6901)
+    ///
+    /// As arrays are transparent, the pointers shouldn't contain array indexes
+    /// (i.e. `/articles/title` selects the title of every article).
+    pub fn from_pointers<I, S>(pointers: I) -> Result<Self, MessyJsonError>
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        let mut res = MessyJsonProjection::new();
+        for pointer in pointers {
+            let tokens: Vec<Cow<str>> = crate::pointer::split(pointer.as_ref())
+                .ok_or_else(|| MessyJsonError::InvalidPointer(pointer.as_ref().to_string()))?
+                .collect();
+            if tokens.is_empty() {
+                return Ok(MessyJsonProjection::new());
+            }
+            res.insert_path(&tokens);
+        }
+        Ok(res)
+    }
+
+    /// Select a field, only keeping the fields of `nested` if it's not empty
+    pub fn with_field(mut self, key: &str, nested: MessyJsonProjection) -> Self {
+        Arc::make_mut(&mut self.0).insert(ArcStr::from(key), nested);
+        self
+    }
+
+    /// Get the projection of a selected field
+    #[inline]
+    pub fn get(&self, key: &str) -> Option<&MessyJsonProjection> {
+        self.0.get(key)
+    }
+
+    /// Get a reference to the selected fields
+    #[inline]
+    pub fn fields(&self) -> &BTreeMap<ArcStr, MessyJsonProjection> {
+        &self.0
+    }
+
+    /// `true` if the projection selects everything
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    fn insert_path(&mut self, tokens: &[Cow<str>]) {
+        let (token, rest) = match tokens.split_first() {
+            Some(x) => x,
+            None => return,
+        };
+        let fields = Arc::make_mut(&mut self.0);
+        match fields.get_mut(token.as_ref()) {
+            // The whole value is already selected
+            Some(nested) if nested.is_empty() => (),
+            Some(_) if rest.is_empty() => {
+                fields.insert(ArcStr::from(token.as_ref()), MessyJsonProjection::new());
+            }
+            Some(nested) => nested.insert_path(rest),
+            None => {
+                let mut nested = MessyJsonProjection::new();
+                nested.insert_path(rest);
+                fields.insert(ArcStr::from(token.as_ref()), nested);
+            }
+        }
+    }
+}
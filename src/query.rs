@@ -0,0 +1,205 @@
+use super::*;
+
+/// A segment of a query string key, like `page` or `[size]` in `page[size]`
+enum Segment<'a> {
+    Key(Cow<'a, str>),
+    /// `[]`, appending to an array
+    Append,
+}
+
+/// Decode a percent-encoded component, `+` standing for a space
+fn decode(input: &str) -> Result<Cow<'_, str>, MessyJsonError> {
+    if !input.contains(['%', '+']) {
+        return Ok(Cow::Borrowed(input));
+    }
+    let mut res: Vec<u8> = Vec::with_capacity(input.len());
+    let mut bytes = input.bytes();
+    while let Some(byte) = bytes.next() {
+        match byte {
+            b'+' => res.push(b' '),
+            b'%' => {
+                let hex = [bytes.next(), bytes.next()];
+                let decoded = match hex {
+                    [Some(h), Some(l)] => std::str::from_utf8(&[h, l])
+                        .ok()
+                        .and_then(|x| u8::from_str_radix(x, 16).ok()),
+                    _ => None,
+                };
+                res.push(decoded.ok_or_else(|| {
+                    MessyJsonError::InvalidQuery(format!("Malformed escape in `{}`", input))
+                })?);
+            }
+            _ => res.push(byte),
+        }
+    }
+    String::from_utf8(res).map(Cow::Owned).map_err(|_| {
+        MessyJsonError::InvalidQuery(format!("`{}` is not valid UTF-8 once decoded", input))
+    })
+}
+
+/// Slice a string, borrowing it if possible
+fn slice<'a>(input: &Cow<'a, str>, start: usize, end: usize) -> Cow<'a, str> {
+    match input {
+        Cow::Borrowed(x) => Cow::Borrowed(&x[start..end]),
+        Cow::Owned(x) => Cow::Owned(x[start..end].to_string()),
+    }
+}
+
+/// Maximum nesting of the keys when [max_depth](MessyJsonLimits::max_depth) isn't set, like
+/// the recursion limit of `serde_json`
+const MAX_DEPTH: usize = 128;
+
+/// Report an exceeded limit
+fn exceeded(limit: MessyJsonLimitExceeded) -> MessyJsonError {
+    MessyJsonError::Validation(serde::de::Error::custom(limit))
+}
+
+/// Split a decoded key using the bracket notation (i.e. `filter[author][name]`).
+///
+/// Each segment nests the value in an array or an object, bounded by the
+/// [limits](MessyJsonSettings::limits).
+fn split_key<'a>(
+    key: &Cow<'a, str>,
+    limits: &MessyJsonLimits,
+) -> Result<Vec<Segment<'a>>, MessyJsonError> {
+    let malformed = || MessyJsonError::InvalidQuery(format!("The key `{}` is malformed", key));
+    let max_depth = limits.max_depth.unwrap_or(MAX_DEPTH);
+    let base_end = key.find('[').unwrap_or_else(|| key.len());
+    if base_end == 0 {
+        return Err(malformed());
+    }
+    limits.check_string_length::<serde::de::value::Error>(base_end)?;
+    let mut res = vec![Segment::Key(slice(key, 0, base_end))];
+    let mut pos = base_end;
+    while pos < key.len() {
+        if res.len() >= max_depth {
+            return Err(exceeded(MessyJsonLimitExceeded::Depth(max_depth)));
+        }
+        if !key[pos..].starts_with('[') {
+            return Err(malformed());
+        }
+        let end = key[pos..].find(']').ok_or_else(malformed)? + pos;
+        limits.check_string_length::<serde::de::value::Error>(end - pos - 1)?;
+        res.push(match end - pos {
+            1 => Segment::Append,
+            _ => Segment::Key(slice(key, pos + 1, end)),
+        });
+        pos = end + 1;
+    }
+    Ok(res)
+}
+
+/// Insert a value at the location described by a key
+fn insert<'a>(
+    mut target: &mut MessyJsonValueRaw<'a>,
+    key: &str,
+    mut path: &[Segment<'a>],
+    value: Cow<'a, str>,
+    settings: &MessyJsonSettings,
+) -> Result<(), MessyJsonError> {
+    let limits = settings.limits();
+    loop {
+        match (target, path) {
+            (MessyJsonValueRaw::Obj(obj), [Segment::Key(name), rest @ ..]) => {
+                let next = match rest.first() {
+                    Some(next) => next,
+                    None => {
+                        if obj.contains_key(name) {
+                            match settings.duplicate_keys() {
+                                MessyJsonDuplicateKeys::Error => {
+                                    return Err(MessyJsonError::InvalidQuery(format!(
+                                        "The key `{}` is duplicated",
+                                        key
+                                    )))
+                                }
+                                MessyJsonDuplicateKeys::FirstWins => return Ok(()),
+                                MessyJsonDuplicateKeys::LastWins => (),
+                            }
+                        }
+                        obj.insert(name.clone(), MessyJsonValueRaw::String(value));
+                        limits.check_keys::<serde::de::value::Error>(obj.len())?;
+                        return Ok(());
+                    }
+                };
+                if !obj.contains_key(name) {
+                    limits.check_keys::<serde::de::value::Error>(obj.len() + 1)?;
+                }
+                target = obj.entry(name.clone()).or_insert_with(|| match next {
+                    Segment::Append => MessyJsonValueRaw::Array(Vec::new()),
+                    Segment::Key(_) => MessyJsonValueRaw::Obj(MessyJsonMap::new()),
+                });
+                path = rest;
+            }
+            (MessyJsonValueRaw::Array(arr), [Segment::Append]) => {
+                arr.push(MessyJsonValueRaw::String(value));
+                limits.check_array_length::<serde::de::value::Error>(arr.len())?;
+                return Ok(());
+            }
+            (MessyJsonValueRaw::Array(_), [Segment::Append, ..]) => {
+                return Err(MessyJsonError::InvalidQuery(format!(
+                    "The key `{}` nests a value in an array",
+                    key
+                )))
+            }
+            _ => {
+                return Err(MessyJsonError::InvalidQuery(format!(
+                    "The key `{}` conflicts with another parameter",
+                    key
+                )))
+            }
+        }
+    }
+}
+
+impl<'a> MessyJsonValueRaw<'a> {
+    /// Parse a query string or an `application/x-www-form-urlencoded` body.
+    ///
+    /// The keys use the bracket notation: `page[size]=10` is parsed as `{ "page": { "size": "10" } }`
+    /// and `ids[]=1&ids[]=2` as `{ "ids": ["1", "2"] }`. Every value is a string.
+    ///
+    /// A key provided more than once is handled according to
+    /// [duplicate_keys](MessyJsonSettings::duplicate_keys). The [limits](MessyJsonSettings::limits)
+    /// are enforced while parsing, the keys being nested at most 128 times when the depth
+    /// isn't limited.
+    pub fn from_query(query: &'a str, settings: MessyJsonSettings) -> Result<Self, MessyJsonError> {
+        if let Some(limit) = settings.limits().document_size_exceeded(query.len()) {
+            return Err(exceeded(limit));
+        }
+        let query = query.strip_prefix('?').unwrap_or(query);
+        let mut res = MessyJsonValueRaw::Obj(MessyJsonMap::new());
+        for pair in query.split('&').filter(|x| !x.is_empty()) {
+            let (key, value) = match pair.find('=') {
+                Some(idx) => (&pair[..idx], &pair[idx + 1..]),
+                None => (pair, ""),
+            };
+            let key = decode(key)?;
+            let path = split_key(&key, settings.limits())?;
+            let value = decode(value)?;
+            settings
+                .limits()
+                .check_string_length::<serde::de::value::Error>(value.len())?;
+            insert(&mut res, &key, &path, value, &settings)?;
+        }
+        Ok(res)
+    }
+}
+
+impl MessyJson {
+    /// Parse a query string or an `application/x-www-form-urlencoded` body, validating it
+    /// against this schema.
+    ///
+    /// See [MessyJsonValueRaw::from_query](MessyJsonValueRaw::from_query) for the syntax.
+    /// The values are [coerced](MessyJsonSettings::coerce_types) to the types of the schema.
+    pub fn parse_query<'a>(
+        &self,
+        query: &'a str,
+        settings: MessyJsonSettings,
+    ) -> Result<MessyJsonValue<'a>, MessyJsonError> {
+        let settings = MessyJsonSettings {
+            coerce_types: true,
+            ..settings
+        };
+        let raw = MessyJsonValueRaw::from_query(query, settings)?;
+        Ok(self.validate_raw_owned(raw, settings)?)
+    }
+}
@@ -1,7 +1,39 @@
 use super::*;
 
-#[derive(Clone, Copy, Default)]
-pub struct MessyJsonValueRawVisitor;
+/// ## Visitor of [MessyJsonValueRaw](MessyJsonValueRaw)
+///
+/// Only the [duplicate_keys](MessyJsonSettings::duplicate_keys) and [limits](MessyJsonSettings::limits)
+/// settings apply to raw values, the visitor can be used as a seed to choose them.
+#[derive(Clone, Default)]
+pub struct MessyJsonValueRawVisitor {
+    settings: MessyJsonSettings,
+    limits: MessyJsonLimitsState,
+}
+
+impl MessyJsonValueRawVisitor {
+    /// Create a new visitor using the provided settings
+    pub fn new(settings: MessyJsonSettings) -> Self {
+        MessyJsonValueRawVisitor {
+            settings,
+            limits: MessyJsonLimitsState::default().track(settings.limits()),
+        }
+    }
+
+    /// Get the settings of the visitor
+    #[inline]
+    pub fn settings(&self) -> &MessyJsonSettings {
+        &self.settings
+    }
+
+    /// Create the visitor of a value nested in the current one
+    #[inline]
+    fn nested(&self) -> Self {
+        MessyJsonValueRawVisitor {
+            settings: self.settings,
+            limits: self.limits.nested(),
+        }
+    }
+}
 
 impl<'de> serde::de::Visitor<'de> for MessyJsonValueRawVisitor {
     type Value = MessyJsonValueRaw<'de>;
@@ -20,6 +52,7 @@ impl<'de> serde::de::Visitor<'de> for MessyJsonValueRawVisitor {
     where
         E: serde::de::Error,
     {
+        self.settings.limits().check_string_length(v.len())?;
         Ok(MessyJsonValueRaw::String(v.into()))
     }
 
@@ -27,6 +60,7 @@ impl<'de> serde::de::Visitor<'de> for MessyJsonValueRawVisitor {
     where
         E: serde::de::Error,
     {
+        self.settings.limits().check_string_length(v.len())?;
         Ok(MessyJsonValueRaw::String(v.to_string().into()))
     }
 
@@ -34,6 +68,7 @@ impl<'de> serde::de::Visitor<'de> for MessyJsonValueRawVisitor {
     where
         E: serde::de::Error,
     {
+        self.settings.limits().check_string_length(v.len())?;
         Ok(MessyJsonValueRaw::String(v.into()))
     }
 
@@ -69,10 +104,26 @@ impl<'de> serde::de::Visitor<'de> for MessyJsonValueRawVisitor {
     where
         A: MapAccess<'de>,
     {
-        let mut res: BTreeMap<Cow<'de, str>, MessyJsonValueRaw<'de>> = BTreeMap::new();
+        let mut res: MessyJsonMap<Cow<'de, str>, MessyJsonValueRaw<'de>> = MessyJsonMap::new();
+        let limits = self.settings.limits();
 
-        while let Some(el) = map.next_entry()? {
-            res.insert(el.0, el.1);
+        self.limits.check_depth(limits)?;
+        let mut count: usize = 0;
+        while let Some(key) = map.next_key_seed(crate::schema_visitor::MessyJsonKeySeed)? {
+            count += 1;
+            limits.check_keys(count)?;
+            limits.check_string_length(key.len())?;
+            let value = map.next_value_seed(self.nested())?;
+            if res.contains_key(&key) {
+                match self.settings.duplicate_keys() {
+                    MessyJsonDuplicateKeys::Error => {
+                        return Err(crate::schema_visitor::messy_json_duplicate_key(&key))
+                    }
+                    MessyJsonDuplicateKeys::FirstWins => continue,
+                    MessyJsonDuplicateKeys::LastWins => (),
+                }
+            }
+            res.insert(key, value);
         }
         Ok(MessyJsonValueRaw::Obj(res))
     }
@@ -85,8 +136,10 @@ impl<'de> serde::de::Visitor<'de> for MessyJsonValueRawVisitor {
         let mut res: Vec<MessyJsonValueRaw<'de>> =
             Vec::with_capacity(seq.size_hint().unwrap_or_default());
 
-        while let Some(next) = seq.next_element()? {
+        self.limits.check_depth(self.settings.limits())?;
+        while let Some(next) = seq.next_element_seed(self.nested())? {
             res.push(next);
+            self.settings.limits().check_array_length(res.len())?;
         }
         Ok(MessyJsonValueRaw::Array(res))
     }
@@ -100,3 +153,15 @@ impl<'de> serde::Deserialize<'de> for MessyJsonValueRaw<'de> {
         deserializer.deserialize_any(MessyJsonValueRawVisitor::default())
     }
 }
+
+impl<'de> DeserializeSeed<'de> for MessyJsonValueRawVisitor {
+    type Value = MessyJsonValueRaw<'de>;
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        self.limits.count_node(self.settings.limits())?;
+        deserializer.deserialize_any(self)
+    }
+}
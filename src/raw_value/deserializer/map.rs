@@ -27,7 +27,7 @@ impl<'de> Deserializer<'de> for MessyJsonRawMapKeyDeserializer<'de> {
 }
 
 pub fn visit_object<'de, V>(
-    object: BTreeMap<Cow<'de, str>, MessyJsonValueRaw<'de>>,
+    object: MessyJsonMap<Cow<'de, str>, MessyJsonValueRaw<'de>>,
     visitor: V,
 ) -> Result<V::Value, serde::de::value::Error>
 where
@@ -48,12 +48,12 @@ where
 }
 
 pub struct MessyJsonRawMapDeserializer<'de> {
-    iter: <BTreeMap<Cow<'de, str>, MessyJsonValueRaw<'de>> as IntoIterator>::IntoIter,
+    iter: <MessyJsonMap<Cow<'de, str>, MessyJsonValueRaw<'de>> as IntoIterator>::IntoIter,
     value: Option<MessyJsonValueRaw<'de>>,
 }
 
 impl<'de> MessyJsonRawMapDeserializer<'de> {
-    pub fn new(map: BTreeMap<Cow<'de, str>, MessyJsonValueRaw<'de>>) -> Self {
+    pub fn new(map: MessyJsonMap<Cow<'de, str>, MessyJsonValueRaw<'de>>) -> Self {
         MessyJsonRawMapDeserializer {
             iter: map.into_iter(),
             value: None,
@@ -4,8 +4,10 @@ mod deserialize;
 mod deserializer;
 #[cfg(test)]
 mod tests;
+mod validate;
 
 pub use deserialize::MessyJsonValueRawVisitor;
+pub(crate) use validate::expected_type;
 
 /// ## Deserialized JSON Value
 ///
@@ -14,13 +16,19 @@ pub use deserialize::MessyJsonValueRawVisitor;
 /// It is structured like [Serde JSON's `Value`](serde_json::Value).
 ///
 /// Every string is borrowed rather than cloned
+///
+/// ### Conversions
+///
+/// This value can be converted from a [serde_json::Value](serde_json::Value), negative and floating point
+/// numbers being cast to `u128` like when deserializing. The conversion to a [serde_json::Value](serde_json::Value)
+/// fails with [MessyJsonError::NumberOverflow](MessyJsonError::NumberOverflow) if a number is bigger than [u64::MAX](u64::MAX).
 #[derive(Clone, Debug, PartialEq, Eq, serde::Serialize)]
 #[serde(untagged)]
 pub enum MessyJsonValueRaw<'a> {
     Array(Vec<MessyJsonValueRaw<'a>>),
     Bool(bool),
     Number(u128),
-    Obj(BTreeMap<Cow<'a, str>, MessyJsonValueRaw<'a>>),
+    Obj(MessyJsonMap<Cow<'a, str>, MessyJsonValueRaw<'a>>),
     String(Cow<'a, str>),
     Null,
 }
@@ -33,6 +33,7 @@ enum DummyJob {
 #[derive(Clone, Debug, Deserialize)]
 struct DummyStruct {
     person: DummyPerson,
+    #[allow(dead_code)]
     employment: DummyJob,
     friends: Vec<String>,
 }
@@ -106,3 +106,35 @@ fn nested_obj_deserialize() {
         _ => panic!("should've been an object"),
     }
 }
+
+#[test]
+fn duplicated_keys() {
+    const VAL: &str = r#"{ "nested": [{ "hello": 1, "hello": 2 }] }"#;
+
+    serde_json::from_str::<MessyJsonValueRaw<'_>>(VAL).unwrap_err();
+    for (duplicate_keys, expected) in [
+        (MessyJsonDuplicateKeys::FirstWins, 1),
+        (MessyJsonDuplicateKeys::LastWins, 2),
+    ]
+    .iter()
+    {
+        let mut deserializer = serde_json::Deserializer::from_str(VAL);
+        let val = MessyJsonValueRawVisitor::new(MessyJsonSettings {
+            duplicate_keys: *duplicate_keys,
+            ..MessyJsonSettings::default()
+        })
+        .deserialize(&mut deserializer)
+        .unwrap();
+
+        match val {
+            MessyJsonValueRaw::Obj(obj) => match obj.get("nested").unwrap() {
+                MessyJsonValueRaw::Array(arr) => assert_eq!(
+                    matches!(&arr[0], MessyJsonValueRaw::Obj(x) if x.get("hello") == Some(&MessyJsonValueRaw::Number(*expected))),
+                    true
+                ),
+                _ => panic!("should've been an array"),
+            },
+            _ => panic!("should've been an object"),
+        }
+    }
+}
@@ -1,4 +1,6 @@
+#![allow(clippy::bool_assert_comparison)]
 use super::*;
 
 mod deserializer;
 mod deserializing;
+mod validate;
@@ -0,0 +1,197 @@
+use super::*;
+
+fn gen_schema() -> MessyJson {
+    let nested_string = MessyJson::from(MessyJsonInner::String(MessyJsonScalar::new(false)));
+    let nested_number = MessyJson::from(MessyJsonInner::Number(MessyJsonNumeric::new(
+        MessyJsonNumberType::U64,
+        false,
+    )));
+    let nested_array = MessyJson::from(MessyJsonInner::Array(MessyJsonArray::new(
+        nested_number,
+        false,
+    )));
+    let nested_opt = MessyJson::from(MessyJsonInner::Bool(MessyJsonScalar::new(true)));
+    MessyJson::from(MessyJsonInner::Obj(MessyJsonObject::from(
+        MessyJsonObjectInner::new(
+            vec![
+                (gen_key("hello"), nested_string),
+                (gen_key("array"), nested_array),
+                (gen_key("opt"), nested_opt),
+            ]
+            .into_iter()
+            .collect(),
+            false,
+        ),
+    )))
+}
+
+#[test]
+fn simple() {
+    const VAL: &str = r#"
+	{
+		"hello": "world",
+		"array": [1, 2, 3]
+	}
+	"#;
+    let schema = gen_schema();
+    let raw: MessyJsonValueRaw<'_> = serde_json::from_str(VAL).unwrap();
+    let parsed = schema
+        .validate_raw(&raw, MessyJsonSettings::default())
+        .unwrap();
+
+    assert_eq!(
+        parsed,
+        serde_json::json!({
+            "hello": "world",
+            "array": [1, 2, 3]
+        })
+    );
+    match parsed {
+        MessyJsonValue::Obj(obj) => {
+            assert_eq!(
+                matches!(obj.get("hello").unwrap(), MessyJsonValue::String(Cow::Borrowed(x)) if *x == "world"),
+                true
+            );
+            assert_eq!(
+                matches!(
+                    obj.get("opt").unwrap(),
+                    MessyJsonValue::Null(MessyJsonNullType::Absent, _)
+                ),
+                true
+            );
+        }
+        _ => panic!("should've been an object"),
+    }
+}
+
+#[test]
+fn owned() {
+    const VAL: &str = r#"
+	{
+		"hello": "world",
+		"array": [],
+		"opt": true
+	}
+	"#;
+    let schema = gen_schema();
+    let raw: MessyJsonValueRaw<'_> = serde_json::from_str(VAL).unwrap();
+    let parsed = schema
+        .validate_raw_owned(raw, MessyJsonSettings::default())
+        .unwrap();
+
+    match parsed {
+        MessyJsonValue::Obj(obj) => {
+            assert_eq!(
+                matches!(obj.get("hello").unwrap(), MessyJsonValue::String(Cow::Borrowed(x)) if *x == "world"),
+                true
+            );
+            assert_eq!(obj.get("opt").unwrap(), &MessyJsonValue::Bool(true));
+        }
+        _ => panic!("should've been an object"),
+    }
+}
+
+#[test]
+fn unknown_key() {
+    const VAL: &str = r#"
+	{
+		"hello": "world",
+		"array": [],
+		"world": "hello"
+	}
+	"#;
+    let schema = gen_schema();
+    let raw: MessyJsonValueRaw<'_> = serde_json::from_str(VAL).unwrap();
+    schema
+        .validate_raw(&raw, MessyJsonSettings::default())
+        .unwrap_err();
+}
+
+#[test]
+fn missing_key() {
+    const VAL: &str = r#"
+	{
+		"array": []
+	}
+	"#;
+    let schema = gen_schema();
+    let raw: MessyJsonValueRaw<'_> = serde_json::from_str(VAL).unwrap();
+    schema
+        .validate_raw(&raw, MessyJsonSettings::default())
+        .unwrap_err();
+    schema
+        .validate_raw(
+            &raw,
+            MessyJsonSettings {
+                all_optional: true,
+                preserve_mandatory: false,
+                ..MessyJsonSettings::default()
+            },
+        )
+        .unwrap();
+}
+
+#[test]
+fn wrong_type() {
+    const VAL: &str = r#"
+	{
+		"hello": "world",
+		"array": ["1"]
+	}
+	"#;
+    let schema = gen_schema();
+    let raw: MessyJsonValueRaw<'_> = serde_json::from_str(VAL).unwrap();
+    schema
+        .validate_raw(&raw, MessyJsonSettings::default())
+        .unwrap_err();
+}
+
+#[test]
+fn big_number() {
+    let schema_u64 = MessyJson::from(MessyJsonInner::Number(MessyJsonNumeric::new(
+        MessyJsonNumberType::U64,
+        false,
+    )));
+    let schema_u128 = MessyJson::from(MessyJsonInner::Number(MessyJsonNumeric::new(
+        MessyJsonNumberType::U128,
+        false,
+    )));
+    let raw = MessyJsonValueRaw::Number(u64::MAX as u128 + 1);
+
+    schema_u64
+        .validate_raw(&raw, MessyJsonSettings::default())
+        .unwrap_err();
+    assert_eq!(
+        schema_u128
+            .validate_raw(&raw, MessyJsonSettings::default())
+            .unwrap(),
+        MessyJsonValue::Number(u64::MAX as u128 + 1)
+    );
+}
+
+#[test]
+fn ignore_unknown_keys() {
+    const VAL: &str = r#"
+	{
+		"hello": "world",
+		"array": [1, 2, 3],
+		"unknown": { "key": true }
+	}
+	"#;
+    let schema = gen_schema();
+    let raw: MessyJsonValueRaw<'_> = serde_json::from_str(VAL).unwrap();
+    schema
+        .validate_raw(&raw, MessyJsonSettings::default())
+        .unwrap_err();
+    let parsed = schema
+        .validate_raw(
+            &raw,
+            MessyJsonSettings {
+                ignore_unknown_keys: true,
+                ..MessyJsonSettings::default()
+            },
+        )
+        .unwrap();
+    assert_eq!(parsed.get("unknown").is_none(), true);
+    assert_eq!(parsed["hello"].as_str(), Some("world"));
+}
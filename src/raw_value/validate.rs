@@ -0,0 +1,218 @@
+use super::*;
+use crate::schema_visitor::{
+    messy_json_check_duplicate, messy_json_check_obj, messy_json_coerce_str,
+    messy_json_forbidden_key, messy_json_unknown_key,
+};
+use crate::sink::MessyJsonObjectSink;
+use serde::de::value::Error;
+use serde::de::Error as _;
+
+/// A node of a [MessyJsonValueRaw](MessyJsonValueRaw) tree, broken down so that it can be
+/// walked whether it is owned or borrowed.
+enum RawNode<'a, S: RawSource<'a>> {
+    Array(S::Seq),
+    Bool(bool),
+    Number(u128),
+    Obj(S::Map),
+    String(Cow<'a, str>),
+    Null,
+}
+
+/// Source of a [MessyJsonValueRaw](MessyJsonValueRaw) tree to validate
+trait RawSource<'a>: Sized {
+    type Key: AsRef<str>;
+    type Seq: ExactSizeIterator<Item = Self>;
+    type Map: Iterator<Item = (Self::Key, Self)>;
+
+    /// Break the source down into a node
+    fn into_node(self) -> RawNode<'a, Self>;
+}
+
+impl<'a> RawSource<'a> for MessyJsonValueRaw<'a> {
+    type Key = Cow<'a, str>;
+    type Seq = std::vec::IntoIter<MessyJsonValueRaw<'a>>;
+    type Map = <MessyJsonMap<Cow<'a, str>, MessyJsonValueRaw<'a>> as IntoIterator>::IntoIter;
+
+    #[inline]
+    fn into_node(self) -> RawNode<'a, Self> {
+        match self {
+            MessyJsonValueRaw::Array(x) => RawNode::Array(x.into_iter()),
+            MessyJsonValueRaw::Bool(x) => RawNode::Bool(x),
+            MessyJsonValueRaw::Number(x) => RawNode::Number(x),
+            MessyJsonValueRaw::Obj(x) => RawNode::Obj(x.into_iter()),
+            MessyJsonValueRaw::String(x) => RawNode::String(x),
+            MessyJsonValueRaw::Null => RawNode::Null,
+        }
+    }
+}
+
+impl<'a, 'b> RawSource<'a> for &'a MessyJsonValueRaw<'b> {
+    type Key = &'a Cow<'b, str>;
+    type Seq = std::slice::Iter<'a, MessyJsonValueRaw<'b>>;
+    type Map = <&'a MessyJsonMap<Cow<'b, str>, MessyJsonValueRaw<'b>> as IntoIterator>::IntoIter;
+
+    #[inline]
+    fn into_node(self) -> RawNode<'a, Self> {
+        match self {
+            MessyJsonValueRaw::Array(x) => RawNode::Array(x.iter()),
+            MessyJsonValueRaw::Bool(x) => RawNode::Bool(*x),
+            MessyJsonValueRaw::Number(x) => RawNode::Number(*x),
+            MessyJsonValueRaw::Obj(x) => RawNode::Obj(x.iter()),
+            MessyJsonValueRaw::String(x) => RawNode::String(Cow::Borrowed(x.as_ref())),
+            MessyJsonValueRaw::Null => RawNode::Null,
+        }
+    }
+}
+
+impl<'a, S: RawSource<'a>> RawNode<'a, S> {
+    /// Describe the node for error reporting
+    fn unexpected(&self) -> serde::de::Unexpected<'_> {
+        match self {
+            RawNode::Array(_) => serde::de::Unexpected::Seq,
+            RawNode::Bool(x) => serde::de::Unexpected::Bool(*x),
+            RawNode::Number(_) => serde::de::Unexpected::Other("number"),
+            RawNode::Obj(_) => serde::de::Unexpected::Map,
+            RawNode::String(x) => serde::de::Unexpected::Str(x.as_ref()),
+            RawNode::Null => serde::de::Unexpected::Unit,
+        }
+    }
+}
+
+fn validate_raw_node<'a, S: RawSource<'a>>(
+    schema: &MessyJson,
+    settings: &MessyJsonSettings,
+    state: &MessyJsonLimitsState,
+    value: S,
+) -> Result<MessyJsonValue<'a>, Error> {
+    let limits = settings.limits();
+    state.count_node(limits)?;
+    let node = value.into_node();
+    match &node {
+        RawNode::String(x) => limits.check_string_length(x.len())?,
+        RawNode::Array(seq) => {
+            state.check_depth(limits)?;
+            limits.check_array_length(seq.len())?;
+        }
+        RawNode::Obj(_) => state.check_depth(limits)?,
+        _ => (),
+    }
+    match (schema.deref(), node) {
+        (_, RawNode::Null) if schema.optional() || settings.all_optional() => {
+            Ok(MessyJsonValue::Null(
+                MessyJsonNullType::Null,
+                MessyJsonExpected::Root(schema.clone()),
+            ))
+        }
+        (MessyJsonInner::Bool(_), RawNode::Bool(x)) => Ok(MessyJsonValue::Bool(x)),
+        (MessyJsonInner::String(_), RawNode::String(x)) => Ok(MessyJsonValue::String(x)),
+        (MessyJsonInner::Number(opt), RawNode::Number(x)) => match opt.type_() {
+            MessyJsonNumberType::U64 if x > u64::MAX as u128 => Err(Error::invalid_value(
+                serde::de::Unexpected::Other("128 bits number"),
+                &"a 64 bits number",
+            )),
+            _ => Ok(MessyJsonValue::Number(x)),
+        },
+        (MessyJsonInner::Bool(_) | MessyJsonInner::Number(_), RawNode::String(x))
+            if settings.coerce_types() =>
+        {
+            messy_json_coerce_str(schema, x.as_ref())
+        }
+        (MessyJsonInner::String(_), RawNode::Number(x)) if settings.coerce_types() => {
+            Ok(MessyJsonValue::String(Cow::Owned(x.to_string())))
+        }
+        #[cfg(feature = "uuid")]
+        (MessyJsonInner::Uuid(_), RawNode::String(x)) => Ok(MessyJsonValue::Uuid(Cow::Owned(
+            feat_uuid::Uuid::parse_str(x.as_ref())
+                .map_err(|e| Error::custom(format!("Failed to deserialize UUID: {}", e)))?,
+        ))),
+        (MessyJsonInner::Array(arr), RawNode::Array(seq)) => {
+            let mut res: Vec<MessyJsonValue> = Vec::with_capacity(seq.len());
+            let nested = state.nested();
+            for elem in seq {
+                res.push(validate_raw_node(arr.items(), settings, &nested, elem)?);
+            }
+            Ok(MessyJsonValue::Array(MessyJsonArrayValue::from(res)))
+        }
+        (MessyJsonInner::Obj(obj), RawNode::Obj(map)) => {
+            let mut res: MessyJsonMap<ArcStr, MessyJsonValue> = MessyJsonMap::new();
+            let mut aliased: BTreeMap<ArcStr, String> = BTreeMap::new();
+            let nested = state.nested();
+            for (count, (key, val)) in map.enumerate() {
+                limits.check_keys(count + 1)?;
+                limits.check_string_length(key.as_ref().len())?;
+                let (position, key_str, val_schema) = match obj
+                    .resolve_property(key.as_ref(), settings.key_matching())
+                    .map_err(serde::de::Error::custom)?
+                {
+                    Some(x) => x,
+                    None if settings.ignore_unknown_keys() => continue,
+                    None => return Err(messy_json_unknown_key(obj, key.as_ref())),
+                };
+                if !obj.allows(key_str, settings) {
+                    return Err(messy_json_forbidden_key(obj, key_str));
+                }
+                if !messy_json_check_duplicate(
+                    &mut aliased,
+                    res.contains(position, key_str),
+                    key_str,
+                    key.as_ref(),
+                    settings,
+                )? {
+                    continue;
+                }
+                res.insert(
+                    key_str.clone(),
+                    validate_raw_node(val_schema, settings, &nested, val)?,
+                );
+            }
+            messy_json_check_obj(obj, settings, &mut res)?;
+            Ok(MessyJsonValue::Obj(MessyJsonObjectValue::from(res)))
+        }
+        (schema, node) => Err(Error::invalid_type(
+            node.unexpected(),
+            &expected_type(schema),
+        )),
+    }
+}
+
+/// Name the type of value expected by a schema, for error reporting
+pub(crate) fn expected_type(schema: &MessyJsonInner) -> &'static str {
+    match schema {
+        MessyJsonInner::Array(_) => "Sequence",
+        MessyJsonInner::Bool(_) => "Bool",
+        MessyJsonInner::Number(_) => "Number",
+        MessyJsonInner::Obj(_) => "Map",
+        MessyJsonInner::String(_) => "String",
+        #[cfg(feature = "uuid")]
+        MessyJsonInner::Uuid(_) => "Uuid",
+    }
+}
+
+impl MessyJson {
+    /// Validate an already parsed [MessyJsonValueRaw](MessyJsonValueRaw) against this schema.
+    ///
+    /// The raw tree is walked directly, without going through a `serde` deserializer, enforcing
+    /// the [limits](MessyJsonSettings::limits). Every string of the resulting value borrows
+    /// from `value`.
+    pub fn validate_raw<'a>(
+        &self,
+        value: &'a MessyJsonValueRaw<'_>,
+        settings: MessyJsonSettings,
+    ) -> Result<MessyJsonValue<'a>, Error> {
+        let state = MessyJsonLimitsState::default().track(settings.limits());
+        validate_raw_node(self, &settings, &state, value)
+    }
+
+    /// Validate an already parsed [MessyJsonValueRaw](MessyJsonValueRaw) against this schema,
+    /// consuming it.
+    ///
+    /// The strings of `value` are moved in the resulting value, preserving borrowed ones.
+    pub fn validate_raw_owned<'a>(
+        &self,
+        value: MessyJsonValueRaw<'a>,
+        settings: MessyJsonSettings,
+    ) -> Result<MessyJsonValue<'a>, Error> {
+        let state = MessyJsonLimitsState::default().track(settings.limits());
+        validate_raw_node(self, &settings, &state, value)
+    }
+}
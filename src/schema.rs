@@ -36,6 +36,27 @@ impl MessyJson {
     pub fn builder(&self, settings: MessyJsonSettings) -> MessyJsonBuilder {
         MessyJsonBuilder::new(self, settings)
     }
+
+    /// Get a mutable reference to the inner schema, cloning it if it's shared
+    #[inline]
+    pub(crate) fn inner_mut(&mut self) -> &mut MessyJsonInner {
+        Arc::make_mut(&mut self.0)
+    }
+
+    /// Look up the schema of a value using a [JSON Pointer](https://tools.ietf.org/html/rfc6901)
+    ///
+    /// Every valid array index, including `-`, points to the schema of the array's items.
+    pub fn pointer(&self, pointer: &str) -> Option<&MessyJson> {
+        crate::pointer::split(pointer)?.try_fold(self, |schema, token| match schema.deref() {
+            MessyJsonInner::Obj(obj) => obj.properties().get(token.as_ref()),
+            MessyJsonInner::Array(arr)
+                if token == "-" || crate::pointer::parse_index(&token).is_some() =>
+            {
+                Some(arr.items())
+            }
+            _ => None,
+        })
+    }
 }
 
 /// An expected object, set when encountering a null value.
@@ -58,6 +79,18 @@ impl MessyJsonInner {
             MessyJsonInner::Uuid(x) => x.optional(),
         }
     }
+
+    pub(crate) fn set_optional(&mut self, optional: bool) {
+        match self {
+            MessyJsonInner::Array(x) => x.set_optional(optional),
+            MessyJsonInner::Bool(x) => x.optional = optional,
+            MessyJsonInner::Number(x) => x.set_optional(optional),
+            MessyJsonInner::Obj(x) => x.inner_mut().set_optional(optional),
+            MessyJsonInner::String(x) => x.optional = optional,
+            #[cfg(feature = "uuid")]
+            MessyJsonInner::Uuid(x) => x.optional = optional,
+        }
+    }
 }
 
 impl From<MessyJsonInner> for MessyJson {
@@ -104,6 +137,10 @@ impl From<&MessyJsonNumeric> for MessyJsonInner {
 pub struct MessyJsonBuilder {
     schema: MessyJson,
     settings: MessyJsonSettings,
+    projection: Option<MessyJsonProjection>,
+    projection_mode: MessyJsonProjectionMode,
+    overrides: Option<MessyJsonSettingsOverrides>,
+    limits: MessyJsonLimitsState,
 }
 
 /// Builder for [MessyJsonObject](MessyJsonObject)
@@ -111,6 +148,10 @@ pub struct MessyJsonBuilder {
 pub struct MessyJsonObjectBuilder {
     schema: MessyJsonObject,
     settings: MessyJsonSettings,
+    projection: Option<MessyJsonProjection>,
+    projection_mode: MessyJsonProjectionMode,
+    overrides: Option<MessyJsonSettingsOverrides>,
+    limits: MessyJsonLimitsState,
 }
 
 pub trait MessyJsonObjectTrait {
@@ -128,74 +169,91 @@ pub trait MessyJsonObjectTrait {
     /// Create a new nested schema providing the nested schema and self
     fn new_nested(&self, schema: &MessyJson, settings: MessyJsonSettings) -> MessyJsonBuilder;
 
-    /// Compare that a deserialized object have all the required fields are available.
-    ///
-    /// Return a missing key if any, None otherwise
-    fn compare_obj(
-        schema: &MessyJsonObject,
-        res: &mut BTreeMap<ArcStr, MessyJsonValue>,
-    ) -> Option<String> {
-        let mut to_be_merged: BTreeMap<ArcStr, MessyJsonValue> = BTreeMap::new();
-        let el = itertools::merge_join_by(schema.properties(), res.keys(), |(key1, _), key2| {
-            Ord::cmp(key1, key2)
-        })
-        .find(|merged| match merged {
-            itertools::EitherOrBoth::Both(_, _) => false,
-            itertools::EitherOrBoth::Left((key, val)) => match val.optional() {
-                true => {
-                    to_be_merged.insert(
-                        (*key).clone(),
-                        MessyJsonValue::Null(
-                            MessyJsonNullType::Absent,
-                            MessyJsonExpected::Root((*val).clone()),
-                        ),
-                    );
-                    false
-                }
-                false => true,
-            },
-            itertools::EitherOrBoth::Right(_) => true,
-        });
-        let missing_key = el.map(|x| {
-            match x {
-                itertools::EitherOrBoth::Both(_, x) => x,
-                itertools::EitherOrBoth::Left((key, _val)) => key.as_str(),
-                itertools::EitherOrBoth::Right(x) => x,
-            }
-            .to_string()
-        });
-        res.append(&mut to_be_merged);
-        missing_key
+    /// Return the projection, if any
+    fn projection(&self) -> Option<&MessyJsonProjection> {
+        None
     }
 
-    /// Compare that a deserialized object have all the required fields either absent or set, but not set to null.
-    ///
-    /// Return a missing key if any, None otherwise
-    fn compare_obj_forced_null(
-        schema: &MessyJsonObject,
-        res: &mut BTreeMap<ArcStr, MessyJsonValue>,
-    ) -> Option<String> {
-        let el = itertools::merge_join_by(schema.properties(), res, |(key1, _), (key2, _)| {
-            Ord::cmp(key1, key2)
-        })
-        .find(|merged| match merged {
-            itertools::EitherOrBoth::Both((_, schema), (_, value)) => {
-				!schema.optional() && matches!(value, MessyJsonValue::Null(null_type, _) if matches!(null_type, MessyJsonNullType::Null))
-			},
-            _ => false,
-        });
-        el.map(|x| {
-            match x {
-                itertools::EitherOrBoth::Both((key, _), _) => key,
-                itertools::EitherOrBoth::Left((key, _val)) => key,
-                itertools::EitherOrBoth::Right((key, _)) => key,
-            }
-            .to_string()
-        })
+    /// Return how the fields left out of the projection are handled
+    fn projection_mode(&self) -> MessyJsonProjectionMode {
+        MessyJsonProjectionMode::default()
+    }
+
+    /// Return the settings overrides of the current value, if any
+    fn overrides(&self) -> Option<&MessyJsonSettingsOverrides> {
+        None
+    }
+
+    /// Return the usage of the limits of the document at the current value
+    fn limits_state(&self) -> &MessyJsonLimitsState;
+
+    /// Create a seed skipping a value nested in the current one, enforcing the limits
+    fn ignored(&self) -> MessyJsonIgnoredAny<'_> {
+        MessyJsonIgnoredAny::new(self.settings().limits(), self.limits_state().nested())
     }
 }
 
-impl<'a> MessyJsonObjectTrait for MessyJsonBuilder {
+impl MessyJsonBuilder {
+    /// Only keep the fields selected by a [MessyJsonProjection](MessyJsonProjection)
+    pub fn with_projection(
+        self,
+        projection: MessyJsonProjection,
+        mode: MessyJsonProjectionMode,
+    ) -> Self {
+        self.project(Some(projection), mode)
+    }
+
+    #[inline]
+    pub(crate) fn project(
+        mut self,
+        projection: Option<MessyJsonProjection>,
+        mode: MessyJsonProjectionMode,
+    ) -> Self {
+        self.projection = projection.filter(|x| !x.is_empty());
+        self.projection_mode = mode;
+        self
+    }
+
+    /// Override the settings of some values, see [MessyJsonSettingsOverrides](MessyJsonSettingsOverrides)
+    pub fn with_overrides(self, overrides: MessyJsonSettingsOverrides) -> Self {
+        self.overridden(Some(&overrides))
+    }
+
+    #[inline]
+    pub(crate) fn overridden(mut self, overrides: Option<&MessyJsonSettingsOverrides>) -> Self {
+        if let Some(settings) = overrides.and_then(MessyJsonSettingsOverrides::settings) {
+            self.settings = settings.apply(&self.settings);
+            self.limits = self.limits.track(self.settings.limits());
+        }
+        self.overrides = overrides.cloned();
+        self
+    }
+}
+
+impl MessyJsonObjectBuilder {
+    /// Only keep the fields selected by a [MessyJsonProjection](MessyJsonProjection)
+    pub fn with_projection(
+        mut self,
+        projection: MessyJsonProjection,
+        mode: MessyJsonProjectionMode,
+    ) -> Self {
+        self.projection = Some(projection).filter(|x| !x.is_empty());
+        self.projection_mode = mode;
+        self
+    }
+
+    /// Override the settings of some values, see [MessyJsonSettingsOverrides](MessyJsonSettingsOverrides)
+    pub fn with_overrides(mut self, overrides: MessyJsonSettingsOverrides) -> Self {
+        if let Some(settings) = overrides.settings() {
+            self.settings = settings.apply(&self.settings);
+            self.limits = self.limits.track(self.settings.limits());
+        }
+        self.overrides = Some(overrides);
+        self
+    }
+}
+
+impl MessyJsonObjectTrait for MessyJsonBuilder {
     type Input = MessyJson;
 
     #[inline]
@@ -203,6 +261,10 @@ impl<'a> MessyJsonObjectTrait for MessyJsonBuilder {
         MessyJsonBuilder {
             schema: schema.clone(),
             settings,
+            projection: None,
+            projection_mode: MessyJsonProjectionMode::default(),
+            overrides: None,
+            limits: MessyJsonLimitsState::default().track(settings.limits()),
         }
     }
 
@@ -216,16 +278,40 @@ impl<'a> MessyJsonObjectTrait for MessyJsonBuilder {
         &self.settings
     }
 
+    #[inline]
+    fn projection(&self) -> Option<&MessyJsonProjection> {
+        self.projection.as_ref()
+    }
+
+    #[inline]
+    fn projection_mode(&self) -> MessyJsonProjectionMode {
+        self.projection_mode
+    }
+
+    #[inline]
+    fn overrides(&self) -> Option<&MessyJsonSettingsOverrides> {
+        self.overrides.as_ref()
+    }
+
+    #[inline]
+    fn limits_state(&self) -> &MessyJsonLimitsState {
+        &self.limits
+    }
+
     #[inline]
     fn new_nested(&self, schema: &MessyJson, settings: MessyJsonSettings) -> MessyJsonBuilder {
         MessyJsonBuilder {
             schema: schema.clone(),
             settings,
+            projection: None,
+            projection_mode: MessyJsonProjectionMode::default(),
+            overrides: None,
+            limits: self.limits.nested().track(settings.limits()),
         }
     }
 }
 
-impl<'a> MessyJsonObjectTrait for MessyJsonObjectBuilder {
+impl MessyJsonObjectTrait for MessyJsonObjectBuilder {
     type Input = MessyJsonObject;
 
     #[inline]
@@ -233,6 +319,10 @@ impl<'a> MessyJsonObjectTrait for MessyJsonObjectBuilder {
         MessyJsonObjectBuilder {
             schema: schema.clone(),
             settings,
+            projection: None,
+            projection_mode: MessyJsonProjectionMode::default(),
+            overrides: None,
+            limits: MessyJsonLimitsState::default().track(settings.limits()),
         }
     }
 
@@ -246,52 +336,126 @@ impl<'a> MessyJsonObjectTrait for MessyJsonObjectBuilder {
         &self.settings
     }
 
+    #[inline]
+    fn projection(&self) -> Option<&MessyJsonProjection> {
+        self.projection.as_ref()
+    }
+
+    #[inline]
+    fn projection_mode(&self) -> MessyJsonProjectionMode {
+        self.projection_mode
+    }
+
+    #[inline]
+    fn overrides(&self) -> Option<&MessyJsonSettingsOverrides> {
+        self.overrides.as_ref()
+    }
+
+    #[inline]
+    fn limits_state(&self) -> &MessyJsonLimitsState {
+        &self.limits
+    }
+
     #[inline]
     fn new_nested(&self, schema: &MessyJson, settings: MessyJsonSettings) -> MessyJsonBuilder {
-        MessyJsonBuilder::new(schema, settings)
+        MessyJsonBuilder {
+            limits: self.limits.nested().track(settings.limits()),
+            ..MessyJsonBuilder::new(schema, settings)
+        }
     }
 }
 
-impl<'de> DeserializeSeed<'de> for MessyJsonBuilder {
-    type Value = MessyJsonValueContainer<'de>;
+/// How a value is requested from the deserializer
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) enum MessyJsonHint {
+    Option,
+    Any,
+    Bool,
+    Str,
+    U64,
+    U128,
+    Map,
+    Seq,
+}
+
+impl MessyJsonHint {
+    /// Request the value from the deserializer
     #[inline]
-    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    pub(crate) fn deserialize<'de, D, V>(
+        self,
+        deserializer: D,
+        visitor: V,
+    ) -> Result<V::Value, D::Error>
     where
         D: Deserializer<'de>,
+        V: Visitor<'de>,
     {
+        match self {
+            MessyJsonHint::Option => deserializer.deserialize_option(visitor),
+            MessyJsonHint::Any => deserializer.deserialize_any(visitor),
+            MessyJsonHint::Bool => deserializer.deserialize_bool(visitor),
+            MessyJsonHint::Str => deserializer.deserialize_str(visitor),
+            MessyJsonHint::U64 => deserializer.deserialize_u64(visitor),
+            MessyJsonHint::U128 => deserializer.deserialize_u128(visitor),
+            MessyJsonHint::Map => deserializer.deserialize_map(visitor),
+            MessyJsonHint::Seq => deserializer.deserialize_seq(visitor),
+        }
+    }
+}
+
+impl MessyJsonBuilder {
+    /// Get how a value that is not `null` is requested from the deserializer
+    #[inline]
+    pub(crate) fn hint(&self) -> MessyJsonHint {
         match self.inner().deref() {
-            MessyJsonInner::Bool(opt) => match opt.optional() || self.settings().all_optional() {
-                true => deserializer.deserialize_option(self),
-                false => deserializer.deserialize_bool(self),
-            },
-            MessyJsonInner::String(opt) => match opt.optional() || self.settings().all_optional() {
-                true => deserializer.deserialize_option(self),
-                false => deserializer.deserialize_str(self),
-            },
-            MessyJsonInner::Number(opt) => match opt.optional() || self.settings().all_optional() {
-                true => deserializer.deserialize_option(self),
-                false => match opt.type_() {
-                    MessyJsonNumberType::U64 => deserializer.deserialize_u64(self),
-                    MessyJsonNumberType::U128 => deserializer.deserialize_u128(self),
-                },
-            },
-            MessyJsonInner::Obj(opt) => match opt.optional() || self.settings().all_optional() {
-                true => deserializer.deserialize_option(self),
-                false => deserializer.deserialize_map(self),
-            },
-            MessyJsonInner::Array(opt) => match opt.optional() || self.settings().all_optional() {
-                true => deserializer.deserialize_option(self),
-                false => deserializer.deserialize_seq(self),
-            },
+            // Numbers bigger than `u64` are only available when requested as such, so they
+            // can't be coerced from strings
+            MessyJsonInner::Number(opt) if opt.type_() == MessyJsonNumberType::U128 => {
+                MessyJsonHint::U128
+            }
+            MessyJsonInner::Bool(_) | MessyJsonInner::Number(_) | MessyJsonInner::String(_)
+                if self.settings().coerce_types() =>
+            {
+                MessyJsonHint::Any
+            }
+            MessyJsonInner::Bool(_) => MessyJsonHint::Bool,
+            MessyJsonInner::String(_) => MessyJsonHint::Str,
+            MessyJsonInner::Number(_) => MessyJsonHint::U64,
+            MessyJsonInner::Obj(_) => MessyJsonHint::Map,
+            MessyJsonInner::Array(_) => MessyJsonHint::Seq,
             #[cfg(feature = "uuid")]
-            MessyJsonInner::Uuid(opt) => match opt.optional() || self.settings().all_optional() {
-                true => deserializer.deserialize_option(self),
-                false => deserializer.deserialize_str(self),
-            },
+            MessyJsonInner::Uuid(_) => MessyJsonHint::Str,
+        }
+    }
+
+    /// Account for a new value in the limits, getting how it is requested from the deserializer
+    #[inline]
+    pub(crate) fn prepare<E>(&self) -> Result<MessyJsonHint, E>
+    where
+        E: serde::de::Error,
+    {
+        self.limits.count_node(self.settings().limits())?;
+        if let MessyJsonInner::Obj(_) | MessyJsonInner::Array(_) = self.inner().deref() {
+            self.limits.check_depth(self.settings().limits())?;
+        }
+        match self.inner().optional() || self.settings().all_optional() {
+            true => Ok(MessyJsonHint::Option),
+            false => Ok(self.hint()),
         }
     }
 }
 
+impl<'de> DeserializeSeed<'de> for MessyJsonBuilder {
+    type Value = MessyJsonValueContainer<'de>;
+    #[inline]
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        self.prepare()?.deserialize(deserializer, self)
+    }
+}
+
 impl<'de> DeserializeSeed<'de> for MessyJsonObjectBuilder {
     type Value = MessyJsonValueContainer<'de>;
     #[inline]
@@ -299,6 +463,8 @@ impl<'de> DeserializeSeed<'de> for MessyJsonObjectBuilder {
     where
         D: Deserializer<'de>,
     {
+        self.limits.count_node(self.settings().limits())?;
+        self.limits.check_depth(self.settings().limits())?;
         match self.inner().optional() {
             true => deserializer.deserialize_option(self),
             false => deserializer.deserialize_map(self),
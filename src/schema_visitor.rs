@@ -1,49 +1,367 @@
 use super::*;
 use crate::schema::MessyJsonObjectTrait;
+use crate::sink::{MessyJsonArraySink, MessyJsonObjectSink};
+use std::collections::BTreeSet;
 
-fn messy_json_visit_map<'de, A, V>(
+/// Seed of an object key, borrowing it from the input when possible.
+///
+/// `Cow<str>` can't be used directly, as its `Deserialize` implementation always allocates.
+pub(crate) struct MessyJsonKeySeed;
+
+impl<'de> DeserializeSeed<'de> for MessyJsonKeySeed {
+    type Value = Cow<'de, str>;
+
+    #[inline]
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_str(self)
+    }
+}
+
+impl<'de> Visitor<'de> for MessyJsonKeySeed {
+    type Value = Cow<'de, str>;
+
+    fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(formatter, "a string")
+    }
+
+    #[inline]
+    fn visit_borrowed_str<E>(self, v: &'de str) -> Result<Self::Value, E> {
+        Ok(Cow::Borrowed(v))
+    }
+
+    #[inline]
+    fn visit_str<E>(self, v: &str) -> Result<Self::Value, E> {
+        Ok(Cow::Owned(v.to_string()))
+    }
+
+    #[inline]
+    fn visit_string<E>(self, v: String) -> Result<Self::Value, E> {
+        Ok(Cow::Owned(v))
+    }
+}
+
+/// Deserialize an object into a [sink](MessyJsonObjectSink), checking it against its schema
+pub(crate) fn messy_json_visit_map<'de, A, V, S>(
     mut seq: A,
     visitor: &V,
     obj: &MessyJsonObject,
-) -> Result<MessyJsonValueContainer<'de>, A::Error>
+    mut res: S,
+) -> Result<S, A::Error>
 where
     A: MapAccess<'de>,
     V: MessyJsonObjectTrait,
+    S: MessyJsonObjectSink<'de>,
+{
+    let mut aliased: BTreeMap<ArcStr, String> = BTreeMap::new();
+    let mut skipped: BTreeSet<ArcStr> = BTreeSet::new();
+    let mut count: usize = 0;
+    while let Some(key_seed) = seq.next_key_seed(MessyJsonKeySeed)? {
+        count += 1;
+        visitor.settings().limits().check_keys(count)?;
+        visitor
+            .settings()
+            .limits()
+            .check_string_length(key_seed.len())?;
+        let (position, key_str, val_schema) = match obj
+            .resolve_property(key_seed.as_ref(), visitor.settings().key_matching())
+            .map_err(serde::de::Error::custom)?
+        {
+            Some(x) => x,
+            None if visitor.settings().ignore_unknown_keys() => {
+                seq.next_value_seed(visitor.ignored())?;
+                continue;
+            }
+            None => return Err(messy_json_unknown_key(obj, key_seed.as_ref())),
+        };
+        if !obj.allows(key_str, visitor.settings()) {
+            return Err(messy_json_forbidden_key(obj, key_str));
+        }
+        if !messy_json_check_duplicate(
+            &mut aliased,
+            res.contains(position, key_str) || skipped.contains(key_str),
+            key_str,
+            key_seed.as_ref(),
+            visitor.settings(),
+        )? {
+            seq.next_value_seed(visitor.ignored())?;
+            continue;
+        }
+        let nested_val = visitor
+            .new_nested(val_schema, *visitor.settings())
+            .overridden(visitor.overrides().and_then(|x| x.get(key_str)));
+        let nested_val = match visitor.projection().map(|x| x.get(key_str)) {
+            None => nested_val,
+            Some(Some(nested_projection)) => {
+                nested_val.project(Some(nested_projection.clone()), visitor.projection_mode())
+            }
+            Some(None) => match visitor.projection_mode() {
+                MessyJsonProjectionMode::Skip => {
+                    skipped.insert(key_str.clone());
+                    seq.next_value_seed(visitor.ignored())?;
+                    continue;
+                }
+                MessyJsonProjectionMode::Validate => nested_val,
+            },
+        };
+        let value = seq.next_value_seed(res.seed(nested_val))?;
+        res.insert(position, key_str, value);
+    }
+    match (visitor.projection(), visitor.projection_mode()) {
+        (None, _) => messy_json_check_obj(obj, visitor.settings(), &mut res)?,
+        (Some(projection), MessyJsonProjectionMode::Validate) => {
+            messy_json_check_obj(obj, visitor.settings(), &mut res)?;
+            res.retain(|key| projection.get(key).is_some());
+        }
+        (Some(projection), MessyJsonProjectionMode::Skip) => {
+            messy_json_check_projected_obj(obj, projection, visitor.settings(), &mut res)?
+        }
+    }
+    Ok(res)
+}
+
+/// Wrap a deserialized object in a [MessyJsonValueContainer](MessyJsonValueContainer)
+#[inline]
+fn messy_json_obj_container(res: MessyJsonMap<ArcStr, MessyJsonValue>) -> MessyJsonValueContainer {
+    MessyJsonValueContainer::new(MessyJsonValue::Obj(MessyJsonObjectValue::from(res)))
+}
+
+/// Deserialize an array into a [sink](MessyJsonArraySink), checking it against its schema
+pub(crate) fn messy_json_visit_seq<'de, A, V, S>(
+    mut seq: A,
+    visitor: &V,
+    arr: &MessyJsonArray,
+    mut res: S,
+) -> Result<S, A::Error>
+where
+    A: SeqAccess<'de>,
+    V: MessyJsonObjectTrait,
+    S: MessyJsonArraySink<'de>,
+{
+    while let Some(elem) = seq.next_element_seed(
+        res.seed(
+            visitor
+                .new_nested(arr.items(), *visitor.settings())
+                .project(visitor.projection().cloned(), visitor.projection_mode())
+                .overridden(visitor.overrides()),
+        ),
+    )? {
+        visitor
+            .settings()
+            .limits()
+            .check_array_length(res.len() + 1)?;
+        res.push(elem)?;
+    }
+    Ok(res)
+}
+
+/// Check that a property isn't provided more than once, `present` telling if it was already
+/// deserialized. Return `false` if the value should be skipped according to
+/// [duplicate_keys](MessyJsonSettings::duplicate_keys).
+///
+/// A property provided through two different aliases is always refused.
+pub(crate) fn messy_json_check_duplicate<E>(
+    aliased: &mut BTreeMap<ArcStr, String>,
+    present: bool,
+    property: &ArcStr,
+    key: &str,
+    settings: &MessyJsonSettings,
+) -> Result<bool, E>
+where
+    E: serde::de::Error,
+{
+    if !present {
+        if property.as_str() != key {
+            aliased.insert(property.clone(), key.to_string());
+        }
+        return Ok(true);
+    }
+    let previous = aliased
+        .get(property)
+        .map(String::as_str)
+        .unwrap_or_else(|| property.as_str());
+    if previous != key {
+        return Err(serde::de::Error::custom(format!(
+            "The key `{}` is provided more than once through its aliases",
+            property
+        )));
+    }
+    match settings.duplicate_keys() {
+        MessyJsonDuplicateKeys::Error => Err(messy_json_duplicate_key(key)),
+        MessyJsonDuplicateKeys::FirstWins => Ok(false),
+        MessyJsonDuplicateKeys::LastWins => Ok(true),
+    }
+}
+
+pub(crate) fn messy_json_duplicate_key<E>(key: &str) -> E
+where
+    E: serde::de::Error,
+{
+    serde::de::Error::custom(format!("The key `{}` is duplicated", key))
+}
+
+/// Convert the string representation of a number or a boolean, see
+/// [coerce_types](MessyJsonSettings::coerce_types)
+pub(crate) fn messy_json_coerce_str<'a, E>(
+    schema: &MessyJsonInner,
+    v: &str,
+) -> Result<MessyJsonValue<'a>, E>
+where
+    E: serde::de::Error,
+{
+    match schema {
+        MessyJsonInner::Bool(_) => match v {
+            "true" => Ok(MessyJsonValue::Bool(true)),
+            "false" => Ok(MessyJsonValue::Bool(false)),
+            _ => Err(serde::de::Error::invalid_value(
+                serde::de::Unexpected::Str(v),
+                &"a boolean",
+            )),
+        },
+        MessyJsonInner::Number(opt) => {
+            let max = match opt.type_() {
+                MessyJsonNumberType::U64 => u64::MAX as u128,
+                MessyJsonNumberType::U128 => u128::MAX,
+            };
+            match v.parse::<u128>() {
+                Ok(x) if x <= max => Ok(MessyJsonValue::Number(x)),
+                _ => Err(serde::de::Error::invalid_value(
+                    serde::de::Unexpected::Str(v),
+                    &"a number",
+                )),
+            }
+        }
+        _ => Err(serde::de::Error::invalid_type(
+            serde::de::Unexpected::Str(v),
+            &crate::raw_value::expected_type(schema),
+        )),
+    }
+}
+
+/// Mark the keys that are not allowed in the direction of the settings as
+/// [absent](MessyJsonNullType::Absent), so they are not required.
+fn messy_json_fill_forbidden<'de, S>(
+    obj: &MessyJsonObject,
+    settings: &MessyJsonSettings,
+    res: &mut S,
+) where
+    S: MessyJsonObjectSink<'de>,
+{
+    if obj.access_restrictions().is_empty() {
+        return;
+    }
+    for (position, (key, val_schema)) in obj.properties().iter().enumerate() {
+        if !obj.access(key).allows(settings.direction()) && !res.contains(position, key) {
+            res.insert_absent(position, key, val_schema);
+        }
+    }
+}
+
+/// Check that a deserialized object contains every mandatory key of its schema,
+/// filling the optional ones with [absent](MessyJsonNullType::Absent) values.
+pub(crate) fn messy_json_check_obj<'de, S, E>(
+    obj: &MessyJsonObject,
+    settings: &MessyJsonSettings,
+    res: &mut S,
+) -> Result<(), E>
+where
+    S: MessyJsonObjectSink<'de>,
+    E: serde::de::Error,
+{
+    messy_json_fill_forbidden(obj, settings, res);
+    if settings.all_optional() && settings.preserve_mandatory() {
+        for (position, (key, val_schema)) in obj.properties().iter().enumerate() {
+            if !val_schema.optional() && res.is_null(position, key) {
+                return Err(serde::de::Error::custom(format!(
+                    "Can't force mandatory key to null `{}`",
+                    key
+                )));
+            }
+        }
+    } else if !settings.all_optional() && obj.properties().len() != res.len() {
+        for (position, (key, val_schema)) in obj.properties().iter().enumerate() {
+            messy_json_check_key(position, key, val_schema, res)?;
+        }
+    }
+    Ok(())
+}
+
+/// Check that a deserialized object contains every mandatory key selected by a projection,
+/// filling the selected optional ones with [absent](MessyJsonNullType::Absent) values.
+fn messy_json_check_projected_obj<'de, S, E>(
+    obj: &MessyJsonObject,
+    projection: &MessyJsonProjection,
+    settings: &MessyJsonSettings,
+    res: &mut S,
+) -> Result<(), E>
+where
+    S: MessyJsonObjectSink<'de>,
+    E: serde::de::Error,
+{
+    if settings.all_optional() {
+        return messy_json_check_obj(obj, settings, res);
+    }
+    messy_json_fill_forbidden(obj, settings, res);
+    for (position, (key, val_schema)) in obj.properties().iter().enumerate() {
+        if projection.get(key).is_some() {
+            messy_json_check_key(position, key, val_schema, res)?;
+        }
+    }
+    Ok(())
+}
+
+/// Check that a mandatory key is set, setting an optional one as
+/// [absent](MessyJsonNullType::Absent) if it's not
+#[inline]
+fn messy_json_check_key<'de, S, E>(
+    position: usize,
+    key: &ArcStr,
+    val_schema: &MessyJson,
+    res: &mut S,
+) -> Result<(), E>
+where
+    S: MessyJsonObjectSink<'de>,
+    E: serde::de::Error,
+{
+    match (res.contains(position, key), val_schema.optional()) {
+        (true, _) => Ok(()),
+        (false, true) => {
+            res.insert_absent(position, key, val_schema);
+            Ok(())
+        }
+        (false, false) => Err(serde::de::Error::custom(format!("Missing key `{}`", key))),
+    }
+}
+
+/// Create the error returned when encountering a key that isn't allowed in the direction of the settings
+pub(crate) fn messy_json_forbidden_key<E>(obj: &MessyJsonObject, key: &str) -> E
+where
+    E: serde::de::Error,
 {
-    let mut res: BTreeMap<ArcStr, MessyJsonValue> = BTreeMap::new();
-    while let Some(key_seed) = seq.next_key::<Cow<'de, str>>()? {
-        let (key_str, val_schema) = obj
-            .properties()
-            .get_key_value(key_seed.as_ref())
-            .ok_or_else(|| {
-                serde::de::Error::custom(format!(
-                    "The key `{}` is unknown. The expected keys were `[ {} ]`",
-                    key_seed,
-                    obj.properties()
-                        .keys()
-                        .map(|s| s.as_str())
-                        .collect::<Vec<&str>>()
-                        .join(", ")
-                ))
-            })?;
-        let nested_val = visitor.new_nested(&val_schema, *visitor.settings());
-        res.insert(key_str.clone(), seq.next_value_seed(nested_val)?.take());
-    }
-    if visitor.settings().all_optional() && visitor.settings().preserve_mandatory() {
-        MessyJsonBuilder::compare_obj_forced_null(obj, &mut res).map_or(Ok(()), |x| {
-            Err(serde::de::Error::custom(format!(
-                "Can't force mandatory key to null `{}`",
-                x
-            )))
-        })?;
-    } else if !visitor.settings().all_optional() && obj.properties().len() != res.len() {
-        MessyJsonBuilder::compare_obj(obj, &mut res).map_or(Ok(()), |x| {
-            Err(serde::de::Error::custom(format!("Missing key `{}`", x)))
-        })?;
-    }
-    Ok(MessyJsonValueContainer::new(MessyJsonValue::Obj(
-        MessyJsonObjectValue::from(res),
-    )))
+    let access = match obj.access(key) {
+        MessyJsonFieldAccess::ReadOnly => "read-only",
+        MessyJsonFieldAccess::WriteOnly => "write-only",
+        MessyJsonFieldAccess::ReadWrite => "not allowed",
+    };
+    serde::de::Error::custom(format!("The key `{}` is {}", key, access))
+}
+
+/// Create the error returned when encountering a key unknown to the schema
+pub(crate) fn messy_json_unknown_key<E>(obj: &MessyJsonObject, key: &str) -> E
+where
+    E: serde::de::Error,
+{
+    serde::de::Error::custom(format!(
+        "The key `{}` is unknown. The expected keys were `[ {} ]`",
+        key,
+        obj.properties()
+            .keys()
+            .map(|s| s.as_str())
+            .collect::<Vec<&str>>()
+            .join(", ")
+    ))
 }
 
 impl<'de> Visitor<'de> for MessyJsonBuilder {
@@ -54,19 +372,14 @@ impl<'de> Visitor<'de> for MessyJsonBuilder {
     }
 
     #[inline]
-    fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+    fn visit_seq<A>(self, seq: A) -> Result<Self::Value, A::Error>
     where
         A: SeqAccess<'de>,
     {
-        let mut res: Vec<MessyJsonValue> = Vec::with_capacity(seq.size_hint().unwrap_or(0));
-
         match self.inner().deref() {
             schema::MessyJsonInner::Array(arr_type) => {
-                while let Some(elem) =
-                    seq.next_element_seed(self.new_nested(arr_type.items(), *self.settings()))?
-                {
-                    res.push(elem.take())
-                }
+                let res = Vec::with_capacity(seq.size_hint().unwrap_or(0));
+                let res = messy_json_visit_seq(seq, &self, arr_type, res)?;
                 Ok(MessyJsonValueContainer::new(MessyJsonValue::Array(
                     MessyJsonArrayValue::from(res),
                 )))
@@ -84,7 +397,10 @@ impl<'de> Visitor<'de> for MessyJsonBuilder {
         A: MapAccess<'de>,
     {
         match self.inner().deref() {
-            schema::MessyJsonInner::Obj(obj_type) => messy_json_visit_map(seq, &self, obj_type),
+            schema::MessyJsonInner::Obj(obj_type) => {
+                messy_json_visit_map(seq, &self, obj_type, MessyJsonMap::new())
+                    .map(messy_json_obj_container)
+            }
             _ => Err(serde::de::Error::invalid_type(
                 serde::de::Unexpected::Map,
                 &"Map",
@@ -113,6 +429,7 @@ impl<'de> Visitor<'de> for MessyJsonBuilder {
     where
         A: serde::de::Error,
     {
+        self.settings().limits().check_string_length(v.len())?;
         match self.inner().deref() {
             schema::MessyJsonInner::String(_) => Ok(MessyJsonValueContainer::new(
                 MessyJsonValue::String(Cow::from(v)),
@@ -123,6 +440,11 @@ impl<'de> Visitor<'de> for MessyJsonBuilder {
                     serde::de::Error::custom(format!("Failed to deserialize UUID: {}", e))
                 })?)),
             )),
+            schema::MessyJsonInner::Bool(_) | schema::MessyJsonInner::Number(_)
+                if self.settings().coerce_types() =>
+            {
+                messy_json_coerce_str(self.inner(), v).map(MessyJsonValueContainer::new)
+            }
             _ => Err(serde::de::Error::invalid_type(
                 serde::de::Unexpected::Str(v),
                 &"String",
@@ -135,6 +457,7 @@ impl<'de> Visitor<'de> for MessyJsonBuilder {
     where
         A: serde::de::Error,
     {
+        self.settings().limits().check_string_length(v.len())?;
         match self.inner().deref() {
             schema::MessyJsonInner::String(_) => Ok(MessyJsonValueContainer::new(
                 MessyJsonValue::String(Cow::from(v.to_string())),
@@ -145,6 +468,11 @@ impl<'de> Visitor<'de> for MessyJsonBuilder {
                     serde::de::Error::custom(format!("Failed to deserialize UUID: {}", e))
                 })?)),
             )),
+            schema::MessyJsonInner::Bool(_) | schema::MessyJsonInner::Number(_)
+                if self.settings().coerce_types() =>
+            {
+                messy_json_coerce_str(self.inner(), v).map(MessyJsonValueContainer::new)
+            }
             _ => Err(serde::de::Error::invalid_type(
                 serde::de::Unexpected::Str(v),
                 &"String",
@@ -157,6 +485,7 @@ impl<'de> Visitor<'de> for MessyJsonBuilder {
     where
         A: serde::de::Error,
     {
+        self.settings().limits().check_string_length(v.len())?;
         match self.inner().deref() {
             schema::MessyJsonInner::String(_) => Ok(MessyJsonValueContainer::new(
                 MessyJsonValue::String(Cow::from(v)),
@@ -167,6 +496,11 @@ impl<'de> Visitor<'de> for MessyJsonBuilder {
                     |e| serde::de::Error::custom(format!("Failed to deserialize UUID: {}", e)),
                 )?)),
             )),
+            schema::MessyJsonInner::Bool(_) | schema::MessyJsonInner::Number(_)
+                if self.settings().coerce_types() =>
+            {
+                messy_json_coerce_str(self.inner(), &v).map(MessyJsonValueContainer::new)
+            }
             _ => Err(serde::de::Error::invalid_type(
                 serde::de::Unexpected::Str(v.as_str()),
                 &"String",
@@ -183,6 +517,9 @@ impl<'de> Visitor<'de> for MessyJsonBuilder {
             schema::MessyJsonInner::Number(_) => Ok(MessyJsonValueContainer::new(
                 MessyJsonValue::Number(v as u128),
             )),
+            schema::MessyJsonInner::String(_) if self.settings().coerce_types() => Ok(
+                MessyJsonValueContainer::new(MessyJsonValue::String(Cow::Owned(v.to_string()))),
+            ),
             _ => Err(serde::de::Error::invalid_type(
                 serde::de::Unexpected::Other("number"),
                 &"Number",
@@ -199,6 +536,9 @@ impl<'de> Visitor<'de> for MessyJsonBuilder {
             schema::MessyJsonInner::Number(_) => {
                 Ok(MessyJsonValueContainer::new(MessyJsonValue::Number(v)))
             }
+            schema::MessyJsonInner::String(_) if self.settings().coerce_types() => Ok(
+                MessyJsonValueContainer::new(MessyJsonValue::String(Cow::Owned(v.to_string()))),
+            ),
             _ => Err(serde::de::Error::invalid_type(
                 serde::de::Unexpected::Other("number"),
                 &"Number",
@@ -222,18 +562,7 @@ impl<'de> Visitor<'de> for MessyJsonBuilder {
     where
         D: Deserializer<'de>,
     {
-        match self.inner().deref() {
-            schema::MessyJsonInner::Bool(_) => deserializer.deserialize_bool(self),
-            schema::MessyJsonInner::String(_) => deserializer.deserialize_str(self),
-            schema::MessyJsonInner::Number(opt) => match opt.type_() {
-                MessyJsonNumberType::U64 => deserializer.deserialize_u64(self),
-                MessyJsonNumberType::U128 => deserializer.deserialize_u128(self),
-            },
-            schema::MessyJsonInner::Obj(_) => deserializer.deserialize_map(self),
-            schema::MessyJsonInner::Array(_) => deserializer.deserialize_seq(self),
-            #[cfg(feature = "uuid")]
-            schema::MessyJsonInner::Uuid(_) => deserializer.deserialize_str(self),
-        }
+        self.hint().deserialize(deserializer, self)
     }
 }
 
@@ -268,6 +597,7 @@ impl<'de> Visitor<'de> for MessyJsonObjectBuilder {
     where
         A: MapAccess<'de>,
     {
-        messy_json_visit_map(seq, &self, self.inner())
+        messy_json_visit_map(seq, &self, self.inner(), MessyJsonMap::new())
+            .map(messy_json_obj_container)
     }
 }
@@ -0,0 +1,88 @@
+use super::*;
+use serde::ser::{Serialize, SerializeMap, SerializeSeq, Serializer};
+
+/// ## Serializable view of a [MessyJsonValue](MessyJsonValue) and its schema
+///
+/// Created using [MessyJson::serializer](MessyJson::serializer).
+///
+/// [Absent](MessyJsonNullType::Absent) keys are skipped, as well as the keys that are not allowed in the
+/// [direction](MessyJsonSettings::direction) of the settings (i.e. write-only keys for an [output](MessyJsonDirection::Output)).
+///
+/// The parts of the value that are unknown to the schema are serialized as is.
+#[derive(Clone, Debug)]
+pub struct MessyJsonSerializer<'v, 'a> {
+    schema: Option<&'v MessyJson>,
+    settings: MessyJsonSettings,
+    value: &'v MessyJsonValue<'a>,
+}
+
+impl<'v, 'a> MessyJsonSerializer<'v, 'a> {
+    fn nested(&self, schema: Option<&'v MessyJson>, value: &'v MessyJsonValue<'a>) -> Self {
+        MessyJsonSerializer {
+            schema,
+            settings: self.settings,
+            value,
+        }
+    }
+}
+
+impl<'v, 'a> Serialize for MessyJsonSerializer<'v, 'a> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        match self.value {
+            MessyJsonValue::Obj(obj) => {
+                let obj_schema = self.schema.and_then(|schema| match schema.deref() {
+                    MessyJsonInner::Obj(x) => Some(x),
+                    _ => None,
+                });
+                let mut map = serializer.serialize_map(None)?;
+                for (key, val) in obj.iter() {
+                    if val.is_absent() || obj_schema.is_some_and(|x| !x.allows(key, &self.settings))
+                    {
+                        continue;
+                    }
+                    let val_schema = obj_schema.and_then(|x| x.properties().get(key));
+                    map.serialize_entry(key.as_str(), &self.nested(val_schema, val))?;
+                }
+                map.end()
+            }
+            MessyJsonValue::Array(arr) => {
+                let items_schema = self.schema.and_then(|schema| match schema.deref() {
+                    MessyJsonInner::Array(x) => Some(x.items()),
+                    _ => None,
+                });
+                let mut seq = serializer.serialize_seq(Some(arr.len()))?;
+                for val in arr.iter() {
+                    seq.serialize_element(&self.nested(items_schema, val))?;
+                }
+                seq.end()
+            }
+            MessyJsonValue::Bool(x) => serializer.serialize_bool(*x),
+            MessyJsonValue::Number(x) if *x <= u64::MAX as u128 => {
+                serializer.serialize_u64(*x as u64)
+            }
+            MessyJsonValue::Number(x) => serializer.serialize_u128(*x),
+            MessyJsonValue::String(x) => serializer.serialize_str(x),
+            #[cfg(feature = "uuid")]
+            MessyJsonValue::Uuid(x) => x.serialize(serializer),
+            MessyJsonValue::Null(_, _) => serializer.serialize_none(),
+        }
+    }
+}
+
+impl MessyJson {
+    /// Create a serializable view of a value using this schema
+    pub fn serializer<'v, 'a>(
+        &'v self,
+        value: &'v MessyJsonValue<'a>,
+        settings: MessyJsonSettings,
+    ) -> MessyJsonSerializer<'v, 'a> {
+        MessyJsonSerializer {
+            schema: Some(self),
+            settings,
+            value,
+        }
+    }
+}
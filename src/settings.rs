@@ -1,3 +1,63 @@
+/// ## Direction of the data
+///
+/// Used to check the [access restrictions](crate::MessyJsonFieldAccess) of the properties.
+#[derive(Clone, Debug, PartialEq, Eq, Copy, Hash, Default)]
+pub enum MessyJsonDirection {
+    /// The access restrictions are ignored
+    #[default]
+    Any,
+    /// The data is received from a client, read-only properties are refused
+    Input,
+    /// The data is sent to a client, write-only properties are refused or skipped
+    Output,
+}
+
+/// ## How the keys of a JSON object are matched with the properties of the schema
+#[derive(Clone, Debug, PartialEq, Eq, Copy, Hash, Default)]
+pub enum MessyJsonKeyMatching {
+    /// The keys must be exactly equal
+    #[default]
+    Exact,
+    /// The keys are compared ignoring their case (i.e. `userId` matches `UserID`)
+    CaseInsensitive,
+    /// The keys are compared ignoring their case, underscores and dashes
+    /// (i.e. `userId` matches `user_id`)
+    Normalized,
+}
+
+impl MessyJsonKeyMatching {
+    /// Check if two keys match
+    pub fn matches(&self, left: &str, right: &str) -> bool {
+        let strip = *self == MessyJsonKeyMatching::Normalized;
+        match self {
+            MessyJsonKeyMatching::Exact => left == right,
+            _ => normalize_key(left, strip).eq(normalize_key(right, strip)),
+        }
+    }
+}
+
+/// ## How an object key provided more than once is handled
+///
+/// Keeping silently one of the values is dangerous when another component reading the same
+/// document keeps the other one, so duplicated keys are refused by default.
+#[derive(Clone, Debug, PartialEq, Eq, Copy, Hash, Default)]
+pub enum MessyJsonDuplicateKeys {
+    /// Duplicated keys are refused
+    #[default]
+    Error,
+    /// The first value is kept, the others are ignored
+    FirstWins,
+    /// The last value is kept
+    LastWins,
+}
+
+/// Lowercase a key, stripping its underscores and dashes if `strip` is set
+fn normalize_key(key: &str, strip: bool) -> impl Iterator<Item = char> + '_ {
+    key.chars()
+        .filter(move |c| !strip || (*c != '_' && *c != '-'))
+        .flat_map(char::to_lowercase)
+}
+
 /// Setting object for deserializing
 #[derive(Clone, Debug, PartialEq, Eq, Copy, Default)]
 pub struct MessyJsonSettings {
@@ -7,6 +67,35 @@ pub struct MessyJsonSettings {
     ///
     /// Ignored if `all_optional` is `false`
     pub preserve_mandatory: bool,
+    /// Skip the keys unknown to the schema instead of failing.
+    ///
+    /// Unknown keys and their values are consumed without being allocated nor tracked, so an
+    /// unknown key provided more than once isn't refused as duplicated.
+    pub ignore_unknown_keys: bool,
+    /// Direction of the data, refusing the properties that are not allowed in this direction.
+    pub direction: MessyJsonDirection,
+    /// How the keys of objects are matched with the properties of the schema.
+    ///
+    /// The values are always stored under the name of the property.
+    pub key_matching: MessyJsonKeyMatching,
+    /// How the keys provided more than once in an object are handled.
+    ///
+    /// The keys left out of a [projection](crate::MessyJsonProjection) are checked as well,
+    /// but not the unknown keys skipped because of `ignore_unknown_keys`.
+    pub duplicate_keys: MessyJsonDuplicateKeys,
+    /// Resource limits of the documents, see [MessyJsonLimits](crate::MessyJsonLimits).
+    pub limits: crate::MessyJsonLimits,
+    /// Accept the string representation of numbers and booleans (i.e. `"42"` or `"true"`),
+    /// and numbers for strings, converting them to the type of the schema.
+    ///
+    /// When deserializing, the values of [U128](crate::MessyJsonNumberType::U128) schemas are
+    /// requested as numbers, as a deserializer can't provide numbers bigger than
+    /// [u64::MAX](u64::MAX) otherwise: their string representation is only accepted by
+    /// [validate_raw](crate::MessyJson::validate_raw) and [parse_query](crate::MessyJson::parse_query).
+    ///
+    /// UUIDs are always read from their string representation, in any of the formats
+    /// accepted by `Uuid::parse_str`, so this setting doesn't change how they're handled.
+    pub coerce_types: bool,
 }
 
 impl MessyJsonSettings {
@@ -17,4 +106,81 @@ impl MessyJsonSettings {
     pub fn preserve_mandatory(&self) -> bool {
         self.preserve_mandatory
     }
+
+    pub fn ignore_unknown_keys(&self) -> bool {
+        self.ignore_unknown_keys
+    }
+
+    pub fn direction(&self) -> MessyJsonDirection {
+        self.direction
+    }
+
+    pub fn key_matching(&self) -> MessyJsonKeyMatching {
+        self.key_matching
+    }
+
+    pub fn duplicate_keys(&self) -> MessyJsonDuplicateKeys {
+        self.duplicate_keys
+    }
+
+    pub fn limits(&self) -> &crate::MessyJsonLimits {
+        &self.limits
+    }
+
+    pub fn coerce_types(&self) -> bool {
+        self.coerce_types
+    }
+}
+
+/// ## Partial [MessyJsonSettings](MessyJsonSettings)
+///
+/// Used by [MessyJsonSettingsOverrides](crate::MessyJsonSettingsOverrides): every field set
+/// replaces the inherited setting, the others are kept as is.
+#[derive(Clone, Debug, PartialEq, Eq, Copy, Default)]
+pub struct MessyJsonPartialSettings {
+    pub all_optional: Option<bool>,
+    pub preserve_mandatory: Option<bool>,
+    pub ignore_unknown_keys: Option<bool>,
+    pub direction: Option<MessyJsonDirection>,
+    pub key_matching: Option<MessyJsonKeyMatching>,
+    pub duplicate_keys: Option<MessyJsonDuplicateKeys>,
+    /// The limits replace the inherited ones as a whole
+    pub limits: Option<crate::MessyJsonLimits>,
+    pub coerce_types: Option<bool>,
+}
+
+impl MessyJsonPartialSettings {
+    /// Apply to some inherited settings
+    pub fn apply(&self, settings: &MessyJsonSettings) -> MessyJsonSettings {
+        MessyJsonSettings {
+            all_optional: self.all_optional.unwrap_or(settings.all_optional),
+            preserve_mandatory: self
+                .preserve_mandatory
+                .unwrap_or(settings.preserve_mandatory),
+            ignore_unknown_keys: self
+                .ignore_unknown_keys
+                .unwrap_or(settings.ignore_unknown_keys),
+            direction: self.direction.unwrap_or(settings.direction),
+            key_matching: self.key_matching.unwrap_or(settings.key_matching),
+            duplicate_keys: self.duplicate_keys.unwrap_or(settings.duplicate_keys),
+            limits: self.limits.unwrap_or(settings.limits),
+            coerce_types: self.coerce_types.unwrap_or(settings.coerce_types),
+        }
+    }
+}
+
+impl From<MessyJsonSettings> for MessyJsonPartialSettings {
+    /// Replace every inherited setting
+    fn from(settings: MessyJsonSettings) -> Self {
+        MessyJsonPartialSettings {
+            all_optional: Some(settings.all_optional),
+            preserve_mandatory: Some(settings.preserve_mandatory),
+            ignore_unknown_keys: Some(settings.ignore_unknown_keys),
+            direction: Some(settings.direction),
+            key_matching: Some(settings.key_matching),
+            duplicate_keys: Some(settings.duplicate_keys),
+            limits: Some(settings.limits),
+            coerce_types: Some(settings.coerce_types),
+        }
+    }
 }
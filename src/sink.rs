@@ -0,0 +1,120 @@
+use super::*;
+
+/// Value produced by the seed of a sink
+pub(crate) type MessyJsonSinkValue<'de, S> = <S as DeserializeSeed<'de>>::Value;
+
+/// ## Container receiving the properties of an object
+///
+/// Lets the [MessyJsonBuilder](MessyJsonBuilder) and the arena share the deserialization and
+/// the checks of the objects. The properties are identified by their name, as well as by their
+/// position among the properties of the schema.
+pub(crate) trait MessyJsonObjectSink<'de> {
+    type Seed: DeserializeSeed<'de>;
+
+    /// Create the seed deserializing the value of a property
+    fn seed(&self, builder: MessyJsonBuilder) -> Self::Seed;
+
+    /// Check if a property is set
+    fn contains(&self, position: usize, key: &str) -> bool;
+
+    /// Check if a property is explicitly set to `null`
+    fn is_null(&self, position: usize, key: &str) -> bool;
+
+    /// Set a property, replacing its previous value
+    fn insert(&mut self, position: usize, key: &ArcStr, value: MessyJsonSinkValue<'de, Self::Seed>);
+
+    /// Set a property as [absent](MessyJsonNullType::Absent)
+    fn insert_absent(&mut self, position: usize, key: &ArcStr, schema: &MessyJson);
+
+    /// Return the number of properties set
+    fn len(&self) -> usize;
+
+    /// Only keep the properties whose name matches the predicate
+    fn retain<F: FnMut(&str) -> bool>(&mut self, f: F);
+}
+
+/// ## Container receiving the elements of an array
+pub(crate) trait MessyJsonArraySink<'de> {
+    type Seed: DeserializeSeed<'de>;
+
+    /// Create the seed deserializing an element
+    fn seed(&self, builder: MessyJsonBuilder) -> Self::Seed;
+
+    /// Append an element, failing to stop the deserialization
+    fn push<E: serde::de::Error>(
+        &mut self,
+        value: MessyJsonSinkValue<'de, Self::Seed>,
+    ) -> Result<(), E>;
+
+    /// Return the number of elements
+    fn len(&self) -> usize;
+}
+
+impl<'de> MessyJsonObjectSink<'de> for MessyJsonMap<ArcStr, MessyJsonValue<'de>> {
+    type Seed = MessyJsonBuilder;
+
+    #[inline]
+    fn seed(&self, builder: MessyJsonBuilder) -> Self::Seed {
+        builder
+    }
+
+    #[inline]
+    fn contains(&self, _position: usize, key: &str) -> bool {
+        self.contains_key(key)
+    }
+
+    #[inline]
+    fn is_null(&self, _position: usize, key: &str) -> bool {
+        matches!(
+            self.get(key),
+            Some(MessyJsonValue::Null(MessyJsonNullType::Null, _))
+        )
+    }
+
+    #[inline]
+    fn insert(&mut self, _position: usize, key: &ArcStr, value: MessyJsonValueContainer<'de>) {
+        MessyJsonMap::insert(self, key.clone(), value.take());
+    }
+
+    #[inline]
+    fn insert_absent(&mut self, _position: usize, key: &ArcStr, schema: &MessyJson) {
+        MessyJsonMap::insert(
+            self,
+            key.clone(),
+            MessyJsonValue::Null(
+                MessyJsonNullType::Absent,
+                MessyJsonExpected::Root(schema.clone()),
+            ),
+        );
+    }
+
+    #[inline]
+    fn len(&self) -> usize {
+        MessyJsonMap::len(self)
+    }
+
+    #[inline]
+    fn retain<F: FnMut(&str) -> bool>(&mut self, mut f: F) {
+        MessyJsonMap::retain(self, |key, _| f(key));
+    }
+}
+
+impl<'de> MessyJsonArraySink<'de> for Vec<MessyJsonValue<'de>> {
+    type Seed = MessyJsonBuilder;
+
+    #[inline]
+    fn seed(&self, builder: MessyJsonBuilder) -> Self::Seed {
+        builder
+    }
+
+    #[inline]
+    fn push<E: serde::de::Error>(&mut self, value: MessyJsonValueContainer<'de>) -> Result<(), E> {
+        Vec::push(self, value.take());
+        Ok(())
+    }
+
+    #[inline]
+    fn len(&self) -> usize {
+        Vec::len(self)
+    }
+}
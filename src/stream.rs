@@ -0,0 +1,112 @@
+use super::*;
+use crate::schema::MessyJsonObjectTrait;
+use crate::schema_visitor::messy_json_visit_seq;
+use crate::sink::MessyJsonArraySink;
+use serde::de::Error as _;
+
+/// Visitor of a root array, handing its items to a callback
+struct ItemsVisitor<F> {
+    builder: MessyJsonBuilder,
+    f: F,
+}
+
+/// Sink handing the items of an array to a callback, only counting them
+struct ItemsSink<F> {
+    f: F,
+    count: usize,
+}
+
+impl<'de, F, E> MessyJsonArraySink<'de> for ItemsSink<F>
+where
+    F: FnMut(MessyJsonValueContainer<'de>) -> Result<(), E>,
+    E: std::fmt::Display,
+{
+    type Seed = MessyJsonBuilder;
+
+    #[inline]
+    fn seed(&self, builder: MessyJsonBuilder) -> Self::Seed {
+        builder
+    }
+
+    #[inline]
+    fn push<Err: serde::de::Error>(
+        &mut self,
+        value: MessyJsonValueContainer<'de>,
+    ) -> Result<(), Err> {
+        self.count += 1;
+        (self.f)(value).map_err(Err::custom)
+    }
+
+    #[inline]
+    fn len(&self) -> usize {
+        self.count
+    }
+}
+
+impl<'de, F, E> Visitor<'de> for ItemsVisitor<F>
+where
+    F: FnMut(MessyJsonValueContainer<'de>) -> Result<(), E>,
+    E: std::fmt::Display,
+{
+    type Value = usize;
+
+    fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(formatter, "an array")
+    }
+
+    fn visit_none<A>(self) -> Result<Self::Value, A>
+    where
+        A: serde::de::Error,
+    {
+        Ok(0)
+    }
+
+    fn visit_some<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_seq(self)
+    }
+
+    fn visit_seq<A>(self, seq: A) -> Result<Self::Value, A::Error>
+    where
+        A: SeqAccess<'de>,
+    {
+        let ItemsVisitor { builder, f } = self;
+        match builder.inner().deref() {
+            MessyJsonInner::Array(arr) => {
+                let sink = ItemsSink { f, count: 0 };
+                Ok(messy_json_visit_seq(seq, &builder, arr, sink)?.count)
+            }
+            _ => Err(A::Error::invalid_type(
+                serde::de::Unexpected::Seq,
+                &"Sequence",
+            )),
+        }
+    }
+}
+
+impl MessyJsonBuilder {
+    /// Validate a root array one item at a time, handing each item to `f` instead of
+    /// collecting them, so the memory stays bounded whatever the size of the array.
+    ///
+    /// Return the number of items. The deserialization stops as soon as `f` fails.
+    pub fn for_each_item<'de, D, F, E>(self, deserializer: D, f: F) -> Result<usize, D::Error>
+    where
+        D: Deserializer<'de>,
+        F: FnMut(MessyJsonValueContainer<'de>) -> Result<(), E>,
+        E: std::fmt::Display,
+    {
+        let optional = match self.inner().deref() {
+            MessyJsonInner::Array(arr) => arr.optional() || self.settings().all_optional(),
+            _ => return Err(D::Error::custom("The schema should be an array")),
+        };
+        self.limits_state().count_node(self.settings().limits())?;
+        self.limits_state().check_depth(self.settings().limits())?;
+        let visitor = ItemsVisitor { builder: self, f };
+        match optional {
+            true => deserializer.deserialize_option(visitor),
+            false => deserializer.deserialize_seq(visitor),
+        }
+    }
+}
@@ -0,0 +1,142 @@
+use super::*;
+
+const VAL: &str = r#"
+{
+	"hello": "world",
+	"number": 15,
+	"bool": true,
+	"null": null,
+	"array": [
+		{
+			"a/b": "slash",
+			"m~n": "tilde"
+		}
+	]
+}
+"#;
+
+fn gen_parser() -> MessyJson {
+    let nested_string = MessyJson::from(MessyJsonInner::String(MessyJsonScalar::new(false)));
+    let nested_number = MessyJson::from(MessyJsonInner::Number(MessyJsonNumeric::new(
+        MessyJsonNumberType::U64,
+        false,
+    )));
+    let nested_bool = MessyJson::from(MessyJsonInner::Bool(MessyJsonScalar::new(false)));
+    let nested_opt = MessyJson::from(MessyJsonInner::String(MessyJsonScalar::new(true)));
+    let nested_obj = MessyJson::from(MessyJsonInner::Obj(MessyJsonObject::from(
+        MessyJsonObjectInner::new(
+            vec![
+                (gen_key("a/b"), nested_string.clone()),
+                (gen_key("m~n"), nested_string.clone()),
+            ]
+            .into_iter()
+            .collect(),
+            false,
+        ),
+    )));
+    let nested_array = MessyJson::from(MessyJsonInner::Array(MessyJsonArray::new(
+        nested_obj, false,
+    )));
+    MessyJson::from(MessyJsonInner::Obj(MessyJsonObject::from(
+        MessyJsonObjectInner::new(
+            vec![
+                (gen_key("hello"), nested_string),
+                (gen_key("number"), nested_number),
+                (gen_key("bool"), nested_bool),
+                (gen_key("null"), nested_opt.clone()),
+                (gen_key("absent"), nested_opt),
+                (gen_key("array"), nested_array),
+            ]
+            .into_iter()
+            .collect(),
+            false,
+        ),
+    )))
+}
+
+#[test]
+fn accessors() {
+    let parser = gen_parser();
+    let mut deserializer = serde_json::Deserializer::from_str(VAL);
+    let parsed: MessyJsonValueContainer = parser
+        .builder(MessyJsonSettings::default())
+        .deserialize(&mut deserializer)
+        .unwrap();
+
+    assert_eq!(parsed.as_object().map(|x| x.len()), Some(6));
+    assert_eq!(
+        parsed.get("hello").and_then(MessyJsonValue::as_str),
+        Some("world")
+    );
+    assert_eq!(parsed["number"].as_u128(), Some(15));
+    assert_eq!(parsed["bool"].as_bool(), Some(true));
+    assert_eq!(parsed["null"].is_null(), true);
+    assert_eq!(parsed["null"].is_absent(), false);
+    assert_eq!(parsed["absent"].is_null(), false);
+    assert_eq!(parsed["absent"].is_absent(), true);
+    assert_eq!(parsed["array"].as_array().map(|x| x.len()), Some(1));
+    assert_eq!(parsed["array"][0]["a/b"].as_str(), Some("slash"));
+    assert_eq!(parsed["hello"].as_u128(), None);
+    assert_eq!(parsed.get("nothing"), None);
+}
+
+#[test]
+fn pointer() {
+    let parser = gen_parser();
+    let mut deserializer = serde_json::Deserializer::from_str(VAL);
+    let parsed: MessyJsonValueContainer = parser
+        .builder(MessyJsonSettings::default())
+        .deserialize(&mut deserializer)
+        .unwrap();
+
+    assert_eq!(parsed.pointer(""), Some(parsed.inner()));
+    assert_eq!(
+        parsed
+            .pointer("/array/0/a~1b")
+            .and_then(MessyJsonValue::as_str),
+        Some("slash")
+    );
+    assert_eq!(
+        parsed
+            .pointer("/array/0/m~0n")
+            .and_then(MessyJsonValue::as_str),
+        Some("tilde")
+    );
+    assert_eq!(parsed.pointer("/array/00/m~0n"), None);
+    assert_eq!(parsed.pointer("/array/1"), None);
+    assert_eq!(parsed.pointer("/hello/world"), None);
+    assert_eq!(parsed.pointer("hello"), None);
+}
+
+#[test]
+#[should_panic]
+fn index_missing_key() {
+    let parser = gen_parser();
+    let mut deserializer = serde_json::Deserializer::from_str(VAL);
+    let parsed: MessyJsonValueContainer = parser
+        .builder(MessyJsonSettings::default())
+        .deserialize(&mut deserializer)
+        .unwrap();
+
+    let _ = &parsed["nothing"];
+}
+
+#[test]
+fn raw_accessors() {
+    let parsed: MessyJsonValueRaw = serde_json::from_str(VAL).unwrap();
+
+    assert_eq!(parsed.as_object().map(|x| x.len()), Some(5));
+    assert_eq!(parsed["hello"].as_str(), Some("world"));
+    assert_eq!(parsed["number"].as_u128(), Some(15));
+    assert_eq!(parsed["bool"].as_bool(), Some(true));
+    assert_eq!(parsed["null"].is_null(), true);
+    assert_eq!(parsed["array"][0]["m~n"].as_str(), Some("tilde"));
+    assert_eq!(
+        parsed
+            .pointer("/array/0/a~1b")
+            .and_then(MessyJsonValueRaw::as_str),
+        Some("slash")
+    );
+    assert_eq!(parsed.pointer("/array/1"), None);
+    assert_eq!(parsed.get("nothing"), None);
+}
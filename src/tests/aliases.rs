@@ -0,0 +1,240 @@
+use super::*;
+
+fn gen_parser() -> MessyJson {
+    let nested_string = MessyJson::from(MessyJsonInner::String(MessyJsonScalar::new(false)));
+    let nested_opt = MessyJson::from(MessyJsonInner::String(MessyJsonScalar::new(true)));
+    MessyJson::from(MessyJsonInner::Obj(MessyJsonObject::from(
+        MessyJsonObjectInner::new(
+            vec![
+                (gen_key("userId"), nested_string),
+                (gen_key("nickname"), nested_opt),
+            ]
+            .into_iter()
+            .collect(),
+            false,
+        )
+        .with_alias("userId", "user_id")
+        .and_then(|x| x.with_alias("nickname", "pseudo"))
+        .unwrap(),
+    )))
+}
+
+fn settings(key_matching: MessyJsonKeyMatching) -> MessyJsonSettings {
+    MessyJsonSettings {
+        key_matching,
+        ..MessyJsonSettings::default()
+    }
+}
+
+fn parse<'a>(
+    parser: &MessyJson,
+    value: &'a str,
+    key_matching: MessyJsonKeyMatching,
+) -> Result<MessyJsonValue<'a>, serde_json::Error> {
+    let mut deserializer = serde_json::Deserializer::from_str(value);
+    parser
+        .builder(settings(key_matching))
+        .deserialize(&mut deserializer)
+        .map(MessyJsonValueContainer::take)
+}
+
+#[test]
+fn alias() {
+    let parser = gen_parser();
+    let parsed = parse(
+        &parser,
+        r#"{ "user_id": "1", "pseudo": "JJ" }"#,
+        MessyJsonKeyMatching::Exact,
+    )
+    .unwrap();
+
+    assert_eq!(
+        parsed,
+        serde_json::json!({
+            "userId": "1",
+            "nickname": "JJ"
+        })
+    );
+    parse(&parser, r#"{ "UserID": "1" }"#, MessyJsonKeyMatching::Exact).unwrap_err();
+}
+
+#[test]
+fn key_matching() {
+    let parser = gen_parser();
+
+    for key in [
+        "userId", "user_id", "UserID", "USERID", "User_Id", "USER-ID",
+    ]
+    .iter()
+    {
+        let value = format!(r#"{{ "{}": "1" }}"#, key);
+        assert_eq!(
+            parse(&parser, &value, MessyJsonKeyMatching::CaseInsensitive)
+                .map(|x| x["userId"].as_str() == Some("1"))
+                .unwrap_or(false),
+            *key != "USER-ID"
+        );
+        assert_eq!(
+            parse(&parser, &value, MessyJsonKeyMatching::Normalized).unwrap()["userId"].as_str(),
+            Some("1")
+        );
+    }
+    assert_eq!(
+        MessyJsonKeyMatching::Normalized.matches("user-id", "USER_ID"),
+        true
+    );
+    assert_eq!(
+        MessyJsonKeyMatching::CaseInsensitive.matches("user-id", "USER_ID"),
+        false
+    );
+}
+
+#[test]
+fn duplicated_alias() {
+    let parser = gen_parser();
+
+    for value in [
+        r#"{ "userId": "1", "user_id": "2" }"#,
+        r#"{ "user_id": "1", "userId": "2" }"#,
+    ]
+    .iter()
+    {
+        let err = parse(&parser, value, MessyJsonKeyMatching::Exact).unwrap_err();
+        assert_eq!(err.to_string().contains("more than once"), true);
+    }
+    parse(
+        &parser,
+        r#"{ "user_id": "1", "user_id": "2" }"#,
+        MessyJsonKeyMatching::Exact,
+    )
+    .unwrap_err();
+    parse(
+        &parser,
+        r#"{ "userId": "1", "USERID": "2" }"#,
+        MessyJsonKeyMatching::CaseInsensitive,
+    )
+    .unwrap_err();
+}
+
+#[test]
+fn raw() {
+    let parser = gen_parser();
+    let raw: MessyJsonValueRaw = serde_json::from_str(r#"{ "user_id": "1" }"#).unwrap();
+    let parsed = parser
+        .validate_raw(&raw, MessyJsonSettings::default())
+        .unwrap();
+
+    assert_eq!(parsed["userId"].as_str(), Some("1"));
+    let raw: MessyJsonValueRaw =
+        serde_json::from_str(r#"{ "user_id": "1", "userId": "2" }"#).unwrap();
+    parser
+        .validate_raw(&raw, MessyJsonSettings::default())
+        .unwrap_err();
+}
+
+#[test]
+fn alias_conflict() {
+    let obj = MessyJsonObjectInner::new(
+        vec![
+            (
+                gen_key("userId"),
+                MessyJson::from(MessyJsonInner::Bool(MessyJsonScalar::new(false))),
+            ),
+            (
+                gen_key("nickname"),
+                MessyJson::from(MessyJsonInner::Bool(MessyJsonScalar::new(false))),
+            ),
+        ]
+        .into_iter()
+        .collect(),
+        false,
+    )
+    .with_alias("userId", "id")
+    .unwrap();
+
+    assert_eq!(
+        obj.clone().with_alias("userId", "nickname"),
+        Err(MessyJsonError::AliasConflict("nickname".to_string()))
+    );
+    assert_eq!(
+        obj.clone().with_alias("nickname", "id"),
+        Err(MessyJsonError::AliasConflict("id".to_string()))
+    );
+    assert_eq!(obj.clone().with_alias("userId", "id"), Ok(obj.clone()));
+    assert_eq!(
+        obj.clone().with_alias("unknown", "other"),
+        Err(MessyJsonError::UnknownProperty("unknown".to_string()))
+    );
+
+    // A property added by a composition shadows the alias
+    let id = MessyJsonObject::from(MessyJsonObjectInner::new(
+        vec![(
+            gen_key("id"),
+            MessyJson::from(MessyJsonInner::Bool(MessyJsonScalar::new(false))),
+        )]
+        .into_iter()
+        .collect(),
+        false,
+    ));
+    assert_eq!(
+        MessyJsonObject::from(obj).extend(&id).aliases().is_empty(),
+        true
+    );
+}
+
+#[test]
+fn ambiguous_key() {
+    let nested_string = MessyJson::from(MessyJsonInner::String(MessyJsonScalar::new(true)));
+    let parser = MessyJson::from(MessyJsonInner::Obj(MessyJsonObject::from(
+        MessyJsonObjectInner::new(
+            vec![
+                (gen_key("userId"), nested_string.clone()),
+                (gen_key("user_id"), nested_string.clone()),
+                (gen_key("name"), nested_string),
+            ]
+            .into_iter()
+            .collect(),
+            false,
+        )
+        .with_alias("name", "USERID")
+        .unwrap(),
+    )));
+
+    // Exact matches are never ambiguous
+    for key in ["userId", "user_id", "USERID"].iter() {
+        let value = format!(r#"{{ "{}": "1" }}"#, key);
+        parse(&parser, &value, MessyJsonKeyMatching::Normalized).unwrap();
+    }
+    let err = parse(
+        &parser,
+        r#"{ "UserId": "1" }"#,
+        MessyJsonKeyMatching::CaseInsensitive,
+    )
+    .unwrap_err();
+    assert_eq!(err.to_string().contains("matches several properties"), true);
+    parse(
+        &parser,
+        r#"{ "User-Id": "1" }"#,
+        MessyJsonKeyMatching::Normalized,
+    )
+    .unwrap_err();
+    assert_eq!(
+        parse(
+            &parser,
+            r#"{ "User_ID": "1" }"#,
+            MessyJsonKeyMatching::CaseInsensitive,
+        )
+        .unwrap()["user_id"]
+            .as_str(),
+        Some("1")
+    );
+    assert_eq!(
+        parser
+            .validate_raw(
+                &serde_json::from_str(r#"{ "USER_ID": "1" }"#).unwrap(),
+                settings(MessyJsonKeyMatching::Normalized)
+            )
+            .map_err(|x| x.to_string().contains("matches several properties")),
+        Err(true)
+    );
+}
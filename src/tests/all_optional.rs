@@ -26,6 +26,7 @@ fn all_absent() {
         .builder(MessyJsonSettings {
             all_optional: true,
             preserve_mandatory: false,
+            ..MessyJsonSettings::default()
         })
         .deserialize(&mut deserializer)
         .unwrap();
@@ -77,6 +78,7 @@ fn mix_absent() {
         .builder(MessyJsonSettings {
             all_optional: true,
             preserve_mandatory: false,
+            ..MessyJsonSettings::default()
         })
         .deserialize(&mut deserializer)
         .unwrap();
@@ -143,6 +145,7 @@ fn all_present() {
         .builder(MessyJsonSettings {
             all_optional: true,
             preserve_mandatory: false,
+            ..MessyJsonSettings::default()
         })
         .deserialize(&mut deserializer)
         .unwrap();
@@ -217,6 +220,7 @@ fn unkown_keys() {
         .builder(MessyJsonSettings {
             all_optional: true,
             preserve_mandatory: false,
+            ..MessyJsonSettings::default()
         })
         .deserialize(&mut deserializer)
         .unwrap_err();
@@ -0,0 +1,233 @@
+use super::*;
+use bumpalo::Bump;
+
+fn gen_parser() -> MessyJson {
+    let nested_string = MessyJson::from(MessyJsonInner::String(MessyJsonScalar::new(false)));
+    let nested_number = MessyJson::from(MessyJsonInner::Number(MessyJsonNumeric::new(
+        MessyJsonNumberType::U64,
+        false,
+    )));
+    let nested_opt = MessyJson::from(MessyJsonInner::String(MessyJsonScalar::new(true)));
+    let nested_comment = MessyJson::from(MessyJsonInner::Obj(MessyJsonObject::from(
+        MessyJsonObjectInner::new(
+            vec![
+                (gen_key("body"), nested_string.clone()),
+                (gen_key("likes"), nested_number),
+            ]
+            .into_iter()
+            .collect(),
+            false,
+        ),
+    )));
+    MessyJson::from(MessyJsonInner::Obj(MessyJsonObject::from(
+        MessyJsonObjectInner::new(
+            vec![
+                (gen_key("title"), nested_string),
+                (gen_key("subtitle"), nested_opt),
+                (
+                    gen_key("comments"),
+                    MessyJson::from(MessyJsonInner::Array(MessyJsonArray::new(
+                        nested_comment,
+                        false,
+                    ))),
+                ),
+            ]
+            .into_iter()
+            .collect(),
+            false,
+        ),
+    )))
+}
+
+const VAL: &str = r#"
+{
+	"title": "Hello",
+	"comments": [
+		{ "body": "First", "likes": 1 },
+		{ "likes": 2, "body": "Second" }
+	]
+}
+"#;
+
+/// Check that deserializing in an arena gives the same result as the builder
+fn same_as_builder(builder: MessyJsonBuilder, value: &str) {
+    let bump = Bump::new();
+    let mut deserializer = serde_json::Deserializer::from_str(value);
+    let expected = builder
+        .clone()
+        .deserialize(&mut deserializer)
+        .map(MessyJsonValueContainer::take);
+    let mut deserializer = serde_json::Deserializer::from_str(value);
+    let parsed = builder
+        .in_arena(&bump)
+        .deserialize(&mut deserializer)
+        .map(|x| x.to_value());
+
+    match (parsed, expected) {
+        (Ok(parsed), Ok(expected)) => assert_eq!(parsed, expected),
+        (Err(parsed), Err(expected)) => assert_eq!(parsed.to_string(), expected.to_string()),
+        (parsed, expected) => panic!("{:?} != {:?}", parsed, expected),
+    }
+}
+
+#[test]
+fn simple() {
+    let parser = gen_parser();
+    let bump = Bump::new();
+    let mut deserializer = serde_json::Deserializer::from_str(VAL);
+    let parsed = parser
+        .builder(MessyJsonSettings::default())
+        .in_arena(&bump)
+        .deserialize(&mut deserializer)
+        .unwrap();
+
+    assert_eq!(parsed["title"].as_str(), Some("Hello"));
+    assert_eq!(parsed["subtitle"].is_absent(), true);
+    assert_eq!(parsed["comments"][1]["body"].as_str(), Some("Second"));
+    assert_eq!(parsed["comments"][1]["likes"].as_u128(), Some(2));
+    assert_eq!(
+        parsed["comments"][1]
+            .as_object()
+            .unwrap()
+            .iter()
+            .map(|(key, _)| key.as_str())
+            .collect::<Vec<_>>(),
+        vec!["likes", "body"]
+    );
+    assert_eq!(
+        parsed.to_value(),
+        serde_json::json!({
+            "title": "Hello",
+            "comments": [
+                { "body": "First", "likes": 1 },
+                { "body": "Second", "likes": 2 }
+            ]
+        })
+    );
+}
+
+#[test]
+fn replaced_keys() {
+    let parser = gen_parser();
+    let bump = Bump::new();
+    let mut deserializer = serde_json::Deserializer::from_str(
+        r#"{ "comments": [], "title": "Hello", "comments": [], "title": "World" }"#,
+    );
+    let parsed = parser
+        .builder(MessyJsonSettings {
+            duplicate_keys: MessyJsonDuplicateKeys::LastWins,
+            ..MessyJsonSettings::default()
+        })
+        .in_arena(&bump)
+        .deserialize(&mut deserializer)
+        .unwrap();
+
+    assert_eq!(parsed["title"].as_str(), Some("World"));
+    assert_eq!(
+        parsed
+            .as_object()
+            .unwrap()
+            .iter()
+            .map(|(key, _)| key.as_str())
+            .collect::<Vec<_>>(),
+        vec!["comments", "title", "subtitle"]
+    );
+}
+
+#[test]
+fn escaped_string() {
+    let parser = gen_parser();
+    let mut bump = Bump::new();
+
+    for _ in 0..3 {
+        {
+            let value = String::from(r#"{ "title": "Hel\"lo", "comments": [] }"#);
+            let mut deserializer = serde_json::Deserializer::from_str(&value);
+            let parsed = parser
+                .builder(MessyJsonSettings::default())
+                .in_arena(&bump)
+                .deserialize(&mut deserializer)
+                .unwrap();
+
+            assert_eq!(parsed["title"].as_str(), Some("Hel\"lo"));
+        }
+        bump.reset();
+    }
+}
+
+#[test]
+fn like_builder() {
+    let parser = gen_parser();
+
+    for value in [
+        VAL,
+        r#"{ "title": "Hello", "subtitle": null, "comments": [] }"#,
+        r#"{ "title": "Hello", "subtitle": "World", "comments": [] }"#,
+        r#"{ "title": "Hello" }"#,
+        r#"{ "title": "Hello", "comments": [], "unknown": 1 }"#,
+        r#"{ "title": "Hello", "title": "World", "comments": [] }"#,
+        r#"{ "title": 1, "comments": [] }"#,
+        r#"{ "title": "Hello", "comments": [{ "body": "First", "likes": "1" }] }"#,
+        r#"{ "title": null, "comments": [{ "body": "First", "likes": null }] }"#,
+        r#"["Hello"]"#,
+    ]
+    .iter()
+    {
+        for settings in [
+            MessyJsonSettings::default(),
+            MessyJsonSettings {
+                all_optional: true,
+                preserve_mandatory: true,
+                ..MessyJsonSettings::default()
+            },
+            MessyJsonSettings {
+                coerce_types: true,
+                ignore_unknown_keys: true,
+                duplicate_keys: MessyJsonDuplicateKeys::LastWins,
+                ..MessyJsonSettings::default()
+            },
+            MessyJsonSettings {
+                limits: MessyJsonLimits {
+                    max_depth: Some(2),
+                    ..MessyJsonLimits::default()
+                },
+                ..MessyJsonSettings::default()
+            },
+        ]
+        .iter()
+        {
+            same_as_builder(parser.builder(*settings), value);
+        }
+        for mode in [
+            MessyJsonProjectionMode::Skip,
+            MessyJsonProjectionMode::Validate,
+        ]
+        .iter()
+        {
+            let projection =
+                MessyJsonProjection::from_pointers(["/subtitle", "/comments/likes"]).unwrap();
+            same_as_builder(
+                parser
+                    .builder(MessyJsonSettings::default())
+                    .with_projection(projection, *mode),
+                value,
+            );
+        }
+    }
+}
+
+#[test]
+fn new_in() {
+    let parser = gen_parser();
+    let bump = Bump::new();
+    let mut deserializer = serde_json::Deserializer::from_str(VAL);
+    let parsed = parser
+        .builder(MessyJsonSettings::default())
+        .deserialize(&mut deserializer)
+        .unwrap()
+        .take();
+    let owned = MessyJsonArenaValue::new_in(parsed.to_owned(), &bump);
+
+    assert_eq!(owned.to_value(), parsed);
+    assert_eq!(owned["comments"][0]["body"].as_str(), Some("First"));
+}
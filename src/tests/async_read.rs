@@ -0,0 +1,197 @@
+use super::*;
+use std::pin::Pin;
+use std::task::{Context, Poll};
+use tokio::io::{AsyncRead, AsyncWriteExt, ReadBuf};
+
+fn gen_parser() -> MessyJson {
+    let nested_string = MessyJson::from(MessyJsonInner::String(MessyJsonScalar::new(false)));
+    let nested_number = MessyJson::from(MessyJsonInner::Number(MessyJsonNumeric::new(
+        MessyJsonNumberType::U64,
+        false,
+    )));
+    MessyJson::from(MessyJsonInner::Obj(MessyJsonObject::from(
+        MessyJsonObjectInner::new(
+            vec![
+                (gen_key("hello"), nested_string),
+                (gen_key("id"), nested_number),
+            ]
+            .into_iter()
+            .collect(),
+            false,
+        ),
+    )))
+}
+
+fn assert_send<T: Send>(x: T) -> T {
+    x
+}
+
+/// Reader yielding a few bytes at a time, being pending before each chunk
+struct Trickle {
+    data: &'static [u8],
+    chunk_size: usize,
+    ready: bool,
+    fail_at_end: bool,
+}
+
+impl Trickle {
+    fn new(data: &'static [u8], chunk_size: usize) -> Self {
+        Trickle {
+            data,
+            chunk_size,
+            ready: false,
+            fail_at_end: false,
+        }
+    }
+
+    /// Fail instead of reaching the end of the data
+    fn failing(self) -> Self {
+        Trickle {
+            fail_at_end: true,
+            ..self
+        }
+    }
+}
+
+impl AsyncRead for Trickle {
+    fn poll_read(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &mut ReadBuf<'_>,
+    ) -> Poll<std::io::Result<()>> {
+        if !self.ready {
+            self.ready = true;
+            cx.waker().wake_by_ref();
+            return Poll::Pending;
+        }
+        if self.data.is_empty() && self.fail_at_end {
+            return Poll::Ready(Err(std::io::Error::other("read past the end")));
+        }
+        let len = self.chunk_size.min(self.data.len()).min(buf.remaining());
+        let (chunk, rest) = self.data.split_at(len);
+        buf.put_slice(chunk);
+        self.data = rest;
+        self.ready = false;
+        Poll::Ready(Ok(()))
+    }
+}
+
+#[tokio::test]
+async fn simple() {
+    let parser = gen_parser();
+    let parsed = assert_send(
+        parser
+            .builder(MessyJsonSettings::default())
+            .deserialize_async(&br#"{ "hello": "world", "id": 1 }"#[..]),
+    )
+    .await
+    .unwrap();
+
+    assert_eq!(
+        matches!(&parsed["hello"], MessyJsonValue::String(Cow::Owned(x)) if x == "world"),
+        true
+    );
+    assert_eq!(parsed["id"], MessyJsonValue::Number(1));
+}
+
+#[tokio::test]
+async fn chunked() {
+    const VAL: &[u8] = br#"{ "hello": "a very long string, split in multiple chunks", "id": 42 }"#;
+    let parser = gen_parser();
+    let (mut writer, reader) = tokio::io::duplex(8);
+    let write = async move {
+        for chunk in VAL.chunks(5) {
+            writer.write_all(chunk).await.unwrap();
+        }
+    };
+    let (_, parsed) = tokio::join!(
+        write,
+        parser
+            .builder(MessyJsonSettings::default())
+            .deserialize_async(reader)
+    );
+
+    assert_eq!(parsed.unwrap()["id"], MessyJsonValue::Number(42));
+}
+
+#[tokio::test]
+async fn pending_chunks() {
+    const VAL: &[u8] = br#"{ "hello": "a very long string, split in multiple chunks", "id": 42 }"#;
+    let parser = gen_parser();
+    let parsed = parser
+        .builder(MessyJsonSettings::default())
+        .deserialize_async(Trickle::new(VAL, 3))
+        .await
+        .unwrap();
+
+    assert_eq!(
+        parsed["hello"].as_str(),
+        Some("a very long string, split in multiple chunks")
+    );
+    assert_eq!(parsed["id"], MessyJsonValue::Number(42));
+}
+
+#[tokio::test]
+async fn incremental() {
+    let parser = gen_parser();
+    // The reader fails if it's read past the invalid value
+    let err = parser
+        .builder(MessyJsonSettings::default())
+        .deserialize_async(Trickle::new(br#"{ "hello": 1, "#, 2).failing())
+        .await
+        .unwrap_err();
+
+    assert_eq!(err.kind(), std::io::ErrorKind::InvalidData);
+}
+
+#[tokio::test]
+async fn invalid() {
+    let parser = gen_parser();
+
+    for val in [
+        &br#"{ "hello": 1, "id": 1 }"#[..],
+        &br#"{ "hello": "world", "id": 1 } []"#[..],
+        &br#"{ "hello": "#[..],
+    ]
+    .iter()
+    {
+        let err = parser
+            .builder(MessyJsonSettings::default())
+            .deserialize_async(*val)
+            .await
+            .unwrap_err();
+        assert_eq!(
+            err.kind() == std::io::ErrorKind::InvalidData
+                || err.kind() == std::io::ErrorKind::UnexpectedEof,
+            true
+        );
+    }
+}
+
+#[tokio::test]
+async fn document_size() {
+    let parser = gen_parser();
+    let settings = |max_document_size| MessyJsonSettings {
+        limits: MessyJsonLimits {
+            max_document_size: Some(max_document_size),
+            ..MessyJsonLimits::default()
+        },
+        ..MessyJsonSettings::default()
+    };
+    const VAL: &[u8] = br#"{ "hello": "world", "id": 1 }"#;
+
+    parser
+        .builder(settings(VAL.len()))
+        .deserialize_async(Trickle::new(VAL, 4))
+        .await
+        .unwrap();
+    let err = parser
+        .builder(settings(VAL.len() - 1))
+        .deserialize_async(Trickle::new(VAL, 4))
+        .await
+        .unwrap_err();
+    assert_eq!(
+        MessyJsonLimitExceeded::from_error(&err),
+        Some(MessyJsonLimitExceeded::DocumentSize(VAL.len() - 1))
+    );
+}
@@ -479,3 +479,121 @@ fn mismatch_obj_nested() {
         "obj comparaison problem"
     );
 }
+
+#[test]
+fn extra_key_in_json() {
+    let parser = gen_parser();
+    let mut deserializer = serde_json::Deserializer::from_str(VAL);
+    let mut parsed_value: serde_json::Value = serde_json::from_str(VAL).unwrap();
+    parsed_value
+        .as_object_mut()
+        .unwrap()
+        .insert("extra".to_string(), serde_json::Value::Null);
+    let parsed: MessyJsonValueContainer = parser
+        .builder(MessyJsonSettings::default())
+        .deserialize(&mut deserializer)
+        .unwrap();
+    assert_eq!(
+        parsed.inner().eq(&parsed_value),
+        false,
+        "obj comparaison problem"
+    );
+    assert_eq!(
+        parsed
+            .inner()
+            .diff_json(&parsed_value, MessyJsonCmpSettings::default()),
+        vec![MessyJsonMismatch {
+            path: "/extra".to_string(),
+            reason: MessyJsonMismatchReason::OnlyInJson
+        }]
+    );
+}
+
+#[test]
+fn absent_vs_missing() {
+    let schema = MessyJsonObject::from(MessyJsonObjectInner::new(
+        vec![(
+            gen_key("hello"),
+            MessyJson::from(MessyJsonInner::String(MessyJsonScalar::new(true))),
+        )]
+        .into_iter()
+        .collect(),
+        false,
+    ));
+    let mut deserializer = serde_json::Deserializer::from_str("{}");
+    let parsed: MessyJsonValueContainer = schema
+        .builder(MessyJsonSettings::default())
+        .deserialize(&mut deserializer)
+        .unwrap();
+    let strict = MessyJsonCmpSettings {
+        absent_eq_missing: false,
+    };
+
+    assert_eq!(parsed.inner().eq(&serde_json::json!({})), true);
+    assert_eq!(
+        parsed.inner().eq(&serde_json::json!({ "hello": null })),
+        false
+    );
+    assert_eq!(
+        parsed.inner().eq_json(&serde_json::json!({}), strict),
+        false
+    );
+    assert_eq!(
+        parsed
+            .inner()
+            .eq_json(&serde_json::json!({ "hello": null }), strict),
+        true
+    );
+    assert_eq!(
+        parsed.inner().diff_json(&serde_json::json!({}), strict),
+        vec![MessyJsonMismatch {
+            path: "/hello".to_string(),
+            reason: MessyJsonMismatchReason::OnlyInMessy
+        }]
+    );
+}
+
+#[test]
+fn big_number() {
+    let big = u64::MAX as u128 + 1;
+    let parsed_value: serde_json::Value = serde_json::from_str(&big.to_string()).unwrap();
+
+    assert_eq!(MessyJsonValue::Number(big).eq(&parsed_value), true);
+    assert_eq!(MessyJsonValue::Number(big + 1).eq(&parsed_value), false);
+    assert_eq!(
+        MessyJsonValue::Number(15).eq(&serde_json::json!(15.5)),
+        false
+    );
+    assert_eq!(
+        MessyJsonValue::Number(15).eq(&serde_json::json!(-15)),
+        false
+    );
+}
+
+#[test]
+fn diff_nested() {
+    let parser = gen_parser();
+    let mut deserializer = serde_json::Deserializer::from_str(VAL);
+    let mut parsed_value: serde_json::Value = serde_json::from_str(VAL).unwrap();
+    parsed_value["obj"]["array"][1] = serde_json::json!("world");
+    parsed_value["obj"]["obj"]["array"] = serde_json::json!([]);
+    let parsed: MessyJsonValueContainer = parser
+        .builder(MessyJsonSettings::default())
+        .deserialize(&mut deserializer)
+        .unwrap();
+    assert_eq!(
+        parsed
+            .inner()
+            .diff_json(&parsed_value, MessyJsonCmpSettings::default()),
+        vec![
+            MessyJsonMismatch {
+                path: "/obj/array/1".to_string(),
+                reason: MessyJsonMismatchReason::Different
+            },
+            MessyJsonMismatch {
+                path: "/obj/obj/array".to_string(),
+                reason: MessyJsonMismatchReason::Length { messy: 4, json: 0 }
+            }
+        ]
+    );
+}
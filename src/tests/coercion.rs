@@ -0,0 +1,127 @@
+use super::*;
+
+fn gen_parser() -> MessyJson {
+    let nested_string = MessyJson::from(MessyJsonInner::String(MessyJsonScalar::new(false)));
+    let nested_bool = MessyJson::from(MessyJsonInner::Bool(MessyJsonScalar::new(true)));
+    let nested_number = MessyJson::from(MessyJsonInner::Number(MessyJsonNumeric::new(
+        MessyJsonNumberType::U64,
+        false,
+    )));
+    let nested_big_number = MessyJson::from(MessyJsonInner::Number(MessyJsonNumeric::new(
+        MessyJsonNumberType::U128,
+        true,
+    )));
+    MessyJson::from(MessyJsonInner::Obj(MessyJsonObject::from(
+        MessyJsonObjectInner::new(
+            vec![
+                (gen_key("name"), nested_string),
+                (gen_key("active"), nested_bool),
+                (gen_key("count"), nested_number),
+                (gen_key("big"), nested_big_number),
+            ]
+            .into_iter()
+            .collect(),
+            false,
+        ),
+    )))
+}
+
+fn settings(coerce_types: bool) -> MessyJsonSettings {
+    MessyJsonSettings {
+        coerce_types,
+        ..MessyJsonSettings::default()
+    }
+}
+
+fn parse(value: &str, coerce_types: bool) -> Result<MessyJsonValue<'_>, serde_json::Error> {
+    let mut deserializer = serde_json::Deserializer::from_str(value);
+    gen_parser()
+        .builder(settings(coerce_types))
+        .deserialize(&mut deserializer)
+        .map(MessyJsonValueContainer::take)
+}
+
+#[test]
+fn coerced() {
+    const VAL: &str = r#"
+	{
+		"name": 42,
+		"active": "true",
+		"count": "15"
+	}
+	"#;
+
+    parse(VAL, false).unwrap_err();
+    let parsed = parse(VAL, true).unwrap();
+    assert_eq!(parsed["name"].as_str(), Some("42"));
+    assert_eq!(parsed["active"], MessyJsonValue::Bool(true));
+    assert_eq!(parsed["count"], MessyJsonValue::Number(15));
+}
+
+#[test]
+fn big_numbers() {
+    const VAL: &str =
+        r#"{ "name": "a", "count": 1, "big": 340282366920938463463374607431768211455 }"#;
+    const VAL_STR: &str =
+        r#"{ "name": "a", "count": 1, "big": "340282366920938463463374607431768211455" }"#;
+
+    assert_eq!(parse(VAL, false).unwrap()["big"].as_u128(), Some(u128::MAX));
+    assert_eq!(parse(VAL, true).unwrap()["big"].as_u128(), Some(u128::MAX));
+    // The string representation is only accepted when validating raw values
+    parse(VAL_STR, true).unwrap_err();
+    let raw: MessyJsonValueRaw = serde_json::from_str(VAL_STR).unwrap();
+    assert_eq!(
+        gen_parser().validate_raw(&raw, settings(true)).unwrap()["big"].as_u128(),
+        Some(u128::MAX)
+    );
+}
+
+#[test]
+fn native_types() {
+    const VAL: &str = r#"{ "name": "hello", "active": null, "count": 15 }"#;
+    let parsed = parse(VAL, true).unwrap();
+
+    assert_eq!(
+        parsed,
+        serde_json::json!({
+            "name": "hello",
+            "active": null,
+            "count": 15
+        })
+    );
+}
+
+#[test]
+fn invalid() {
+    for val in [
+        r#"{ "name": "a", "count": "-1" }"#,
+        r#"{ "name": "a", "count": "18446744073709551616" }"#,
+        r#"{ "name": "a", "count": "1.5" }"#,
+        r#"{ "name": "a", "count": 1, "active": "yes" }"#,
+        r#"{ "name": true, "count": 1 }"#,
+    ]
+    .iter()
+    {
+        parse(val, true).unwrap_err();
+    }
+}
+
+#[test]
+fn raw() {
+    let raw: MessyJsonValueRaw =
+        serde_json::from_str(r#"{ "name": 42, "active": "false", "count": "15" }"#).unwrap();
+    let parser = gen_parser();
+
+    parser
+        .validate_raw(&raw, MessyJsonSettings::default())
+        .unwrap_err();
+    let parsed = parser.validate_raw(&raw, settings(true)).unwrap();
+    assert_eq!(
+        parsed,
+        serde_json::json!({
+            "name": "42",
+            "active": false,
+            "count": 15
+        })
+    );
+}
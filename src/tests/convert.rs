@@ -0,0 +1,154 @@
+use super::*;
+use serde_json::{json, Value};
+use std::convert::TryFrom;
+
+fn gen_schema() -> MessyJson {
+    let nested_string = MessyJson::from(MessyJsonInner::String(MessyJsonScalar::new(false)));
+    let nested_number = MessyJson::from(MessyJsonInner::Number(MessyJsonNumeric::new(
+        MessyJsonNumberType::U128,
+        false,
+    )));
+    let nested_opt = MessyJson::from(MessyJsonInner::String(MessyJsonScalar::new(true)));
+    MessyJson::from(MessyJsonInner::Obj(MessyJsonObject::from(
+        MessyJsonObjectInner::new(
+            vec![
+                (gen_key("hello"), nested_string),
+                (gen_key("number"), nested_number),
+                (gen_key("opt"), nested_opt),
+            ]
+            .into_iter()
+            .collect(),
+            false,
+        ),
+    )))
+}
+
+#[test]
+fn value_to_json() {
+    let schema = gen_schema();
+    let value = r#"
+	{
+		"hello": "world",
+		"number": 15
+	}
+	"#;
+    let mut deserializer = serde_json::Deserializer::from_str(value);
+    let parsed = schema
+        .builder(MessyJsonSettings::default())
+        .deserialize(&mut deserializer)
+        .unwrap()
+        .take();
+
+    assert_eq!(
+        Value::try_from(parsed).unwrap(),
+        json!({
+            "hello": "world",
+            "number": 15
+        }),
+        "Absent keys should have been removed"
+    );
+}
+
+#[test]
+fn value_to_raw() {
+    let schema = gen_schema();
+    let value = r#"
+	{
+		"hello": "world",
+		"number": 15,
+		"opt": null
+	}
+	"#;
+    let mut deserializer = serde_json::Deserializer::from_str(value);
+    let parsed = schema
+        .builder(MessyJsonSettings::default())
+        .deserialize(&mut deserializer)
+        .unwrap()
+        .take();
+    let raw = MessyJsonValueRaw::from(parsed);
+
+    match raw {
+        MessyJsonValueRaw::Obj(obj) => {
+            assert_eq!(obj.len(), 3);
+            assert_eq!(obj.get("opt").unwrap(), &MessyJsonValueRaw::Null);
+            assert_eq!(
+                obj.get("hello").unwrap(),
+                &MessyJsonValueRaw::String(Cow::Borrowed("world"))
+            );
+        }
+        _ => panic!("should've been an object"),
+    }
+}
+
+#[test]
+fn number_overflow() {
+    let val = MessyJsonValue::Number(u64::MAX as u128 + 1);
+
+    assert_eq!(
+        Value::try_from(val).unwrap_err(),
+        MessyJsonError::NumberOverflow(u64::MAX as u128 + 1)
+    );
+    assert_eq!(
+        Value::try_from(MessyJsonValue::Number(u64::MAX as u128)).unwrap(),
+        json!(u64::MAX)
+    );
+}
+
+#[test]
+fn json_to_raw_and_back() {
+    let value = json!({
+        "hello": "world",
+        "array": [1, true, null],
+        "nested": {
+            "key": "value"
+        }
+    });
+    let raw = MessyJsonValueRaw::from(&value);
+
+    match &raw {
+        MessyJsonValueRaw::Obj(obj) => assert_eq!(
+            matches!(obj.get("hello").unwrap(), MessyJsonValueRaw::String(Cow::Borrowed(x)) if *x == "world"),
+            true
+        ),
+        _ => panic!("should've been an object"),
+    }
+    assert_eq!(Value::try_from(raw).unwrap(), value);
+    assert_eq!(
+        Value::try_from(MessyJsonValueRaw::from(value.clone())).unwrap(),
+        value
+    );
+}
+
+#[test]
+fn json_to_value() {
+    let schema = gen_schema();
+    let value = json!({
+        "hello": "world",
+        "number": 15
+    });
+    let parsed = schema
+        .validate_value(&value, MessyJsonSettings::default())
+        .unwrap();
+
+    assert_eq!(parsed, value);
+    schema
+        .validate_value(&json!({ "hello": 15 }), MessyJsonSettings::default())
+        .unwrap_err();
+}
+
+#[test]
+fn raw_borrowed_keys() {
+    let raw: MessyJsonValueRaw = serde_json::from_str(r#"{ "hello": 1, "wor\"ld": 2 }"#).unwrap();
+
+    match &raw {
+        MessyJsonValueRaw::Obj(obj) => {
+            assert_eq!(
+                obj.keys()
+                    .map(|key| matches!(key, Cow::Borrowed(_)))
+                    .collect::<Vec<_>>(),
+                vec![true, false]
+            );
+        }
+        _ => panic!("should've been an object"),
+    }
+}
@@ -0,0 +1,185 @@
+use super::*;
+
+fn gen_parser() -> MessyJson {
+    let nested_string = MessyJson::from(MessyJsonInner::String(MessyJsonScalar::new(false)));
+    let nested_opt = MessyJson::from(MessyJsonInner::String(MessyJsonScalar::new(true)));
+    let nested_number = MessyJson::from(MessyJsonInner::Number(MessyJsonNumeric::new(
+        MessyJsonNumberType::U64,
+        false,
+    )));
+    let nested_array = MessyJson::from(MessyJsonInner::Array(MessyJsonArray::new(
+        nested_number,
+        false,
+    )));
+    let nested_obj = MessyJson::from(MessyJsonInner::Obj(MessyJsonObject::from(
+        MessyJsonObjectInner::new(
+            vec![
+                (gen_key("city"), nested_string.clone()),
+                (gen_key("street"), nested_opt.clone()),
+            ]
+            .into_iter()
+            .collect(),
+            false,
+        ),
+    )));
+    MessyJson::from(MessyJsonInner::Obj(MessyJsonObject::from(
+        MessyJsonObjectInner::new(
+            vec![
+                (gen_key("name"), nested_string),
+                (gen_key("nickname"), nested_opt.clone()),
+                (gen_key("alias"), nested_opt),
+                (gen_key("address"), nested_obj),
+                (gen_key("numbers"), nested_array),
+            ]
+            .into_iter()
+            .collect(),
+            false,
+        ),
+    )))
+}
+
+fn parse<'a>(parser: &MessyJson, value: &'a str) -> MessyJsonValue<'a> {
+    let mut deserializer = serde_json::Deserializer::from_str(value);
+    parser
+        .builder(MessyJsonSettings::default())
+        .deserialize(&mut deserializer)
+        .unwrap()
+        .take()
+}
+
+const OLD: &str = r#"
+{
+	"name": "Jean",
+	"nickname": "JJ",
+	"address": {
+		"city": "Paris",
+		"street": "Rue de Rivoli"
+	},
+	"numbers": [1, 2, 3]
+}
+"#;
+
+const NEW: &str = r#"
+{
+	"name": "Jean",
+	"nickname": null,
+	"alias": "Jojo",
+	"address": {
+		"city": "Lyon"
+	},
+	"numbers": [1, 4]
+}
+"#;
+
+#[test]
+fn changes() {
+    let parser = gen_parser();
+    let old = parse(&parser, OLD);
+    let new = parse(&parser, NEW);
+    let diff = parser.diff(&old, &new);
+
+    let summary: Vec<(&str, &str)> = diff
+        .changes()
+        .iter()
+        .map(|change| match change {
+            MessyJsonChange::Added { path, .. } => ("added", path.as_str()),
+            MessyJsonChange::Removed { path, .. } => ("removed", path.as_str()),
+            MessyJsonChange::Modified { path, .. } => ("modified", path.as_str()),
+        })
+        .collect();
+    assert_eq!(
+        summary,
+        vec![
+            ("modified", "/address/city"),
+            ("removed", "/address/street"),
+            ("added", "/alias"),
+            ("modified", "/nickname"),
+            ("modified", "/numbers/1"),
+            ("removed", "/numbers/2"),
+        ]
+    );
+    match &diff.changes()[3] {
+        MessyJsonChange::Modified { old, new, .. } => {
+            assert_eq!(old.as_str(), Some("JJ"));
+            assert_eq!(new.is_null(), true);
+        }
+        _ => panic!("should've been modified"),
+    }
+    assert_eq!(parser.diff(&old, &old).is_empty(), true);
+}
+
+#[test]
+fn null_vs_absent() {
+    let parser = gen_parser();
+    let old = parse(
+        &parser,
+        r#"{ "name": "Jean", "address": { "city": "Paris" }, "numbers": [] }"#,
+    );
+    let new = parse(
+        &parser,
+        r#"{ "name": "Jean", "nickname": null, "address": { "city": "Paris" }, "numbers": [] }"#,
+    );
+
+    assert_eq!(old["nickname"].is_absent(), true);
+    let diff = parser.diff(&old, &new);
+    assert_eq!(diff.changes().len(), 1);
+    assert_eq!(
+        matches!(&diff.changes()[0], MessyJsonChange::Added { path, value } if path == "/nickname" && value.is_null()),
+        true
+    );
+    let diff = parser.diff(&new, &old);
+    assert_eq!(
+        matches!(&diff.changes()[0], MessyJsonChange::Removed { path, .. } if path == "/nickname"),
+        true
+    );
+}
+
+#[test]
+fn to_json_patch() {
+    let parser = gen_parser();
+    let mut old = parse(&parser, OLD);
+    let new = parse(&parser, NEW);
+    let patch = parser.diff(&old, &new).to_json_patch();
+
+    parser
+        .edit(&mut old, MessyJsonSettings::default())
+        .apply_patch(&patch)
+        .unwrap();
+    assert_eq!(parser.diff(&old, &new).is_empty(), true);
+}
+
+#[test]
+fn to_merge_patch() {
+    let parser = gen_parser();
+    let mut old = parse(&parser, OLD);
+    let new = parse(&parser, NEW);
+    let patch = parser.diff(&old, &new).to_merge_patch();
+
+    assert_eq!(
+        patch,
+        serde_json::json!({
+            "address": {
+                "city": "Lyon",
+                "street": null
+            },
+            "alias": "Jojo",
+            "nickname": null,
+            "numbers": [1, 4]
+        })
+    );
+    parser
+        .edit(&mut old, MessyJsonSettings::default())
+        .apply_merge_patch(&patch)
+        .unwrap();
+    assert_eq!(
+        old,
+        serde_json::json!({
+            "name": "Jean",
+            "alias": "Jojo",
+            "address": {
+                "city": "Lyon"
+            },
+            "numbers": [1, 4]
+        })
+    );
+}
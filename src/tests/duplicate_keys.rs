@@ -0,0 +1,160 @@
+use super::*;
+
+fn gen_parser() -> MessyJson {
+    let nested_string = MessyJson::from(MessyJsonInner::String(MessyJsonScalar::new(false)));
+    MessyJson::from(MessyJsonInner::Obj(MessyJsonObject::from(
+        MessyJsonObjectInner::new(
+            vec![(gen_key("hello"), nested_string)]
+                .into_iter()
+                .collect(),
+            false,
+        )
+        .with_alias("hello", "hi")
+        .unwrap(),
+    )))
+}
+
+fn parse<'a>(
+    parser: &MessyJson,
+    value: &'a str,
+    duplicate_keys: MessyJsonDuplicateKeys,
+) -> Result<MessyJsonValue<'a>, serde_json::Error> {
+    let mut deserializer = serde_json::Deserializer::from_str(value);
+    parser
+        .builder(MessyJsonSettings {
+            duplicate_keys,
+            ..MessyJsonSettings::default()
+        })
+        .deserialize(&mut deserializer)
+        .map(MessyJsonValueContainer::take)
+}
+
+#[test]
+fn error() {
+    let parser = gen_parser();
+    let err = parse(
+        &parser,
+        r#"{ "hello": "a", "hello": "b" }"#,
+        MessyJsonDuplicateKeys::Error,
+    )
+    .unwrap_err();
+
+    assert_eq!(err.to_string().contains("duplicated"), true);
+    parse(
+        &parser,
+        r#"{ "hi": "a", "hi": "b" }"#,
+        MessyJsonDuplicateKeys::Error,
+    )
+    .unwrap_err();
+}
+
+#[test]
+fn first_wins() {
+    let parser = gen_parser();
+    let parsed = parse(
+        &parser,
+        r#"{ "hello": "a", "hello": 1 }"#,
+        MessyJsonDuplicateKeys::FirstWins,
+    )
+    .unwrap();
+
+    assert_eq!(parsed, serde_json::json!({ "hello": "a" }));
+}
+
+#[test]
+fn last_wins() {
+    let parser = gen_parser();
+    let parsed = parse(
+        &parser,
+        r#"{ "hi": "a", "hi": "b" }"#,
+        MessyJsonDuplicateKeys::LastWins,
+    )
+    .unwrap();
+
+    assert_eq!(parsed, serde_json::json!({ "hello": "b" }));
+}
+
+#[test]
+fn aliases_always_refused() {
+    let parser = gen_parser();
+
+    for duplicate_keys in [
+        MessyJsonDuplicateKeys::FirstWins,
+        MessyJsonDuplicateKeys::LastWins,
+    ]
+    .iter()
+    {
+        parse(&parser, r#"{ "hello": "a", "hi": "b" }"#, *duplicate_keys).unwrap_err();
+    }
+}
+
+fn parse_skipped<'a>(
+    parser: &MessyJson,
+    value: &'a str,
+    duplicate_keys: MessyJsonDuplicateKeys,
+    projection: Option<MessyJsonProjection>,
+) -> Result<MessyJsonValue<'a>, serde_json::Error> {
+    let mut deserializer = serde_json::Deserializer::from_str(value);
+    let builder = parser.builder(MessyJsonSettings {
+        duplicate_keys,
+        ignore_unknown_keys: true,
+        all_optional: true,
+        ..MessyJsonSettings::default()
+    });
+    match projection {
+        Some(projection) => builder
+            .with_projection(projection, MessyJsonProjectionMode::Skip)
+            .deserialize(&mut deserializer),
+        None => builder.deserialize(&mut deserializer),
+    }
+    .map(MessyJsonValueContainer::take)
+}
+
+#[test]
+fn skipped_keys() {
+    let parser = gen_parser();
+    let projection = MessyJsonProjection::new().with_field("other", MessyJsonProjection::new());
+
+    // Unknown keys aren't tracked
+    for duplicate_keys in [
+        MessyJsonDuplicateKeys::Error,
+        MessyJsonDuplicateKeys::FirstWins,
+    ]
+    .iter()
+    {
+        parse_skipped(
+            &parser,
+            r#"{ "other": 1, "other": 2 }"#,
+            *duplicate_keys,
+            None,
+        )
+        .unwrap();
+    }
+
+    // Keys left out of the projection
+    let err = parse_skipped(
+        &parser,
+        r#"{ "hello": "a", "hello": "b" }"#,
+        MessyJsonDuplicateKeys::Error,
+        Some(projection.clone()),
+    )
+    .unwrap_err();
+    assert_eq!(err.to_string().contains("duplicated"), true);
+    parse_skipped(
+        &parser,
+        r#"{ "hello": "a", "hi": "b" }"#,
+        MessyJsonDuplicateKeys::LastWins,
+        Some(projection.clone()),
+    )
+    .unwrap_err();
+    assert_eq!(
+        parse_skipped(
+            &parser,
+            r#"{ "hello": "a", "hello": "b" }"#,
+            MessyJsonDuplicateKeys::LastWins,
+            Some(projection),
+        )
+        .unwrap(),
+        serde_json::json!({})
+    );
+}
@@ -0,0 +1,360 @@
+use super::*;
+use serde::de::Error as _;
+
+fn gen_parser() -> MessyJson {
+    let nested_string = MessyJson::from(MessyJsonInner::String(MessyJsonScalar::new(true)));
+    let nested_number = MessyJson::from(MessyJsonInner::Number(MessyJsonNumeric::new(
+        MessyJsonNumberType::U64,
+        false,
+    )));
+    let nested_array = MessyJson::from(MessyJsonInner::Array(MessyJsonArray::new(
+        nested_number,
+        true,
+    )));
+    let nested_obj = MessyJson::from(MessyJsonInner::Obj(MessyJsonObject::from(
+        MessyJsonObjectInner::new(
+            vec![(gen_key("array"), nested_array.clone())]
+                .into_iter()
+                .collect(),
+            true,
+        ),
+    )));
+    MessyJson::from(MessyJsonInner::Obj(MessyJsonObject::from(
+        MessyJsonObjectInner::new(
+            vec![
+                (gen_key("hello"), nested_string),
+                (gen_key("array"), nested_array),
+                (gen_key("nested"), nested_obj),
+            ]
+            .into_iter()
+            .collect(),
+            false,
+        ),
+    )))
+}
+
+fn parse(value: &str, limits: MessyJsonLimits) -> Result<MessyJsonValueContainer<'_>, String> {
+    let mut deserializer = serde_json::Deserializer::from_str(value);
+    gen_parser()
+        .builder(MessyJsonSettings {
+            limits,
+            ..MessyJsonSettings::default()
+        })
+        .deserialize(&mut deserializer)
+        .map_err(|e| e.to_string())
+}
+
+fn parse_raw(value: &str, limits: MessyJsonLimits) -> Result<MessyJsonValueRaw<'_>, String> {
+    let mut deserializer = serde_json::Deserializer::from_str(value);
+    MessyJsonValueRawVisitor::new(MessyJsonSettings {
+        limits,
+        ..MessyJsonSettings::default()
+    })
+    .deserialize(&mut deserializer)
+    .map_err(|e| e.to_string())
+}
+
+/// Validate an already parsed value
+fn validate(value: &str, limits: MessyJsonLimits) -> Result<(), String> {
+    let raw = parse_raw(value, MessyJsonLimits::default()).unwrap();
+    gen_parser()
+        .validate_raw(
+            &raw,
+            MessyJsonSettings {
+                limits,
+                ..MessyJsonSettings::default()
+            },
+        )
+        .map(|_| ())
+        .map_err(|e| e.to_string())
+}
+
+#[test]
+fn depth() {
+    const VAL: &str = r#"{ "nested": { "array": [1] } }"#;
+    let limits = |max_depth| MessyJsonLimits {
+        max_depth: Some(max_depth),
+        ..MessyJsonLimits::default()
+    };
+
+    parse(VAL, limits(3)).unwrap();
+    let err = parse(VAL, limits(2)).unwrap_err();
+    assert_eq!(err.contains("the maximum depth is 2"), true);
+    parse_raw(VAL, limits(3)).unwrap();
+    parse_raw(VAL, limits(2)).unwrap_err();
+    validate(VAL, limits(3)).unwrap();
+    validate(VAL, limits(2)).unwrap_err();
+}
+
+#[test]
+fn keys() {
+    const VAL: &str = r#"{ "hello": "world", "array": [] }"#;
+    let limits = |max_keys| MessyJsonLimits {
+        max_keys: Some(max_keys),
+        ..MessyJsonLimits::default()
+    };
+
+    parse(VAL, limits(2)).unwrap();
+    let err = parse(VAL, limits(1)).unwrap_err();
+    assert_eq!(err.contains("more than 1 keys"), true);
+    parse_raw(VAL, limits(2)).unwrap();
+    parse_raw(VAL, limits(1)).unwrap_err();
+    validate(VAL, limits(2)).unwrap();
+    validate(VAL, limits(1)).unwrap_err();
+}
+
+#[test]
+fn array_length() {
+    const VAL: &str = r#"{ "array": [1, 2, 3] }"#;
+    let limits = |max_array_length| MessyJsonLimits {
+        max_array_length: Some(max_array_length),
+        ..MessyJsonLimits::default()
+    };
+
+    parse(VAL, limits(3)).unwrap();
+    let err = parse(VAL, limits(2)).unwrap_err();
+    assert_eq!(err.contains("more than 2 items"), true);
+    parse_raw(VAL, limits(3)).unwrap();
+    parse_raw(VAL, limits(2)).unwrap_err();
+    validate(VAL, limits(3)).unwrap();
+    validate(VAL, limits(2)).unwrap_err();
+}
+
+#[test]
+fn string_length() {
+    const VAL: &str = r#"{ "hello": "world" }"#;
+    let limits = |max_string_length| MessyJsonLimits {
+        max_string_length: Some(max_string_length),
+        ..MessyJsonLimits::default()
+    };
+
+    parse(VAL, limits(5)).unwrap();
+    let err = parse(VAL, limits(4)).unwrap_err();
+    assert_eq!(err.contains("longer than 4 bytes"), true);
+    parse_raw(VAL, limits(5)).unwrap();
+    parse_raw(VAL, limits(4)).unwrap_err();
+    validate(VAL, limits(5)).unwrap();
+    validate(VAL, limits(4)).unwrap_err();
+    // The keys are bounded as well
+    parse_raw(r#"{ "hello": "" }"#, limits(4)).unwrap_err();
+}
+
+#[test]
+fn nodes() {
+    const VAL: &str = r#"{ "hello": "world", "nested": { "array": [1, 2] } }"#;
+    let limits = |max_nodes| MessyJsonLimits {
+        max_nodes: Some(max_nodes),
+        ..MessyJsonLimits::default()
+    };
+
+    parse(VAL, limits(6)).unwrap();
+    let err = parse(VAL, limits(5)).unwrap_err();
+    assert_eq!(err.contains("more than 5 values"), true);
+    parse_raw(VAL, limits(6)).unwrap();
+    parse_raw(VAL, limits(5)).unwrap_err();
+    validate(VAL, limits(6)).unwrap();
+    validate(VAL, limits(5)).unwrap_err();
+}
+
+#[test]
+fn overridden() {
+    const VAL: &str = r#"{ "hello": "world", "nested": { "array": [1, 2] } }"#;
+    let overrides = MessyJsonSettingsOverrides::new().with_pointer(
+        "/nested",
+        MessyJsonPartialSettings {
+            limits: Some(MessyJsonLimits {
+                max_nodes: Some(2),
+                ..MessyJsonLimits::default()
+            }),
+            ..MessyJsonPartialSettings::default()
+        },
+    );
+    let mut deserializer = serde_json::Deserializer::from_str(VAL);
+    gen_parser()
+        .builder(MessyJsonSettings::default())
+        .with_overrides(overrides.unwrap())
+        .deserialize(&mut deserializer)
+        .unwrap_err();
+}
+
+#[test]
+fn from_error() {
+    let limits = MessyJsonLimits {
+        max_string_length: Some(4),
+        max_nodes: Some(12),
+        ..MessyJsonLimits::default()
+    };
+    let err = parse(r#"{ "hello": "world" }"#, limits).unwrap_err();
+    assert_eq!(
+        MessyJsonLimitExceeded::from_error(&err),
+        Some(MessyJsonLimitExceeded::StringLength(4))
+    );
+
+    for limit in [
+        MessyJsonLimitExceeded::Depth(1),
+        MessyJsonLimitExceeded::Keys(12),
+        MessyJsonLimitExceeded::ArrayLength(0),
+        MessyJsonLimitExceeded::StringLength(4),
+        MessyJsonLimitExceeded::Nodes(100),
+    ]
+    .iter()
+    {
+        let err = serde_json::Error::custom(limit);
+        assert_eq!(MessyJsonLimitExceeded::from_error(&err), Some(*limit));
+    }
+    assert_eq!(
+        MessyJsonLimitExceeded::from_error(&"Limit exceeded: the maximum depth is"),
+        None
+    );
+    assert_eq!(
+        MessyJsonLimitExceeded::from_error(&"The key `hello` is duplicated"),
+        None
+    );
+}
+
+#[test]
+fn skipped_values() {
+    let parse_skipped = |value: &str, limits, projection: Option<MessyJsonProjection>| {
+        let mut deserializer = serde_json::Deserializer::from_str(value);
+        let builder = gen_parser().builder(MessyJsonSettings {
+            limits,
+            ignore_unknown_keys: true,
+            duplicate_keys: MessyJsonDuplicateKeys::FirstWins,
+            ..MessyJsonSettings::default()
+        });
+        match projection {
+            Some(projection) => builder
+                .with_projection(projection, MessyJsonProjectionMode::Skip)
+                .deserialize(&mut deserializer),
+            None => builder.deserialize(&mut deserializer),
+        }
+        .map(|_| ())
+        .map_err(|e| MessyJsonLimitExceeded::from_error(&e))
+    };
+    let projection = MessyJsonProjection::new().with_field("hello", MessyJsonProjection::new());
+
+    // Unknown keys
+    parse_skipped(
+        r#"{ "other": [[["deep"]]] }"#,
+        MessyJsonLimits::default(),
+        None,
+    )
+    .unwrap();
+    assert_eq!(
+        parse_skipped(
+            r#"{ "other": [[["deep"]]] }"#,
+            MessyJsonLimits {
+                max_depth: Some(3),
+                ..MessyJsonLimits::default()
+            },
+            None
+        ),
+        Err(Some(MessyJsonLimitExceeded::Depth(3)))
+    );
+    assert_eq!(
+        parse_skipped(
+            r#"{ "other": { "a": "b", "c": "d" } }"#,
+            MessyJsonLimits {
+                max_keys: Some(1),
+                ..MessyJsonLimits::default()
+            },
+            None
+        ),
+        Err(Some(MessyJsonLimitExceeded::Keys(1)))
+    );
+    // Duplicated keys
+    assert_eq!(
+        parse_skipped(
+            r#"{ "hello": "world", "hello": "too long" }"#,
+            MessyJsonLimits {
+                max_string_length: Some(5),
+                ..MessyJsonLimits::default()
+            },
+            None
+        ),
+        Err(Some(MessyJsonLimitExceeded::StringLength(5)))
+    );
+    // Keys left out of the projection
+    assert_eq!(
+        parse_skipped(
+            r#"{ "hello": "world", "array": [1, 2, 3] }"#,
+            MessyJsonLimits {
+                max_array_length: Some(2),
+                ..MessyJsonLimits::default()
+            },
+            Some(projection.clone())
+        ),
+        Err(Some(MessyJsonLimitExceeded::ArrayLength(2)))
+    );
+    assert_eq!(
+        parse_skipped(
+            r#"{ "hello": "world", "array": [1, 2, 3] }"#,
+            MessyJsonLimits {
+                max_nodes: Some(5),
+                ..MessyJsonLimits::default()
+            },
+            Some(projection)
+        ),
+        Err(Some(MessyJsonLimitExceeded::Nodes(5)))
+    );
+}
+
+#[test]
+fn document_size() {
+    let settings = MessyJsonSettings {
+        limits: MessyJsonLimits {
+            max_document_size: Some(20),
+            ..MessyJsonLimits::default()
+        },
+        ..MessyJsonSettings::default()
+    };
+    let parser = gen_parser();
+    let lines = "{ \"hello\": \"world\" }\n{ \"hello\": \"the whole world\" }\n{}\n";
+
+    let parsed: Vec<_> = parser
+        .parse_lines(lines.as_bytes(), settings)
+        .skip_invalid()
+        .map(|x| x.map_err(|e| MessyJsonLimitExceeded::from_error(&e)))
+        .collect();
+    assert_eq!(parsed.len(), 3);
+    assert_eq!(parsed[0].is_ok(), true);
+    assert_eq!(
+        parsed[1].as_ref().unwrap_err(),
+        &Some(MessyJsonLimitExceeded::DocumentSize(20))
+    );
+    assert_eq!(parsed[2].is_ok(), true);
+    let parsed: Vec<_> = parser
+        .parse_lines_str(lines, settings)
+        .skip_invalid()
+        .map(|x| x.is_ok())
+        .collect();
+    assert_eq!(parsed, vec![true, false, true]);
+
+    MessyJsonValueRaw::from_query("hello=the+world", settings).unwrap();
+    let err = MessyJsonValueRaw::from_query("hello=the+whole+world", settings).unwrap_err();
+    assert_eq!(
+        MessyJsonLimitExceeded::from_error(&err),
+        Some(MessyJsonLimitExceeded::DocumentSize(20))
+    );
+}
+
+#[test]
+fn query() {
+    let settings = MessyJsonSettings {
+        limits: MessyJsonLimits {
+            max_keys: Some(1),
+            ..MessyJsonLimits::default()
+        },
+        ..MessyJsonSettings::default()
+    };
+    let parser = gen_parser();
+
+    parser.parse_query("hello=world", settings).unwrap();
+    let err = parser
+        .parse_query("hello=world&array[]=1", settings)
+        .unwrap_err();
+    assert_eq!(
+        MessyJsonLimitExceeded::from_error(&err),
+        Some(MessyJsonLimitExceeded::Keys(1))
+    );
+}
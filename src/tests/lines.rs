@@ -0,0 +1,135 @@
+use super::*;
+
+fn gen_parser() -> MessyJson {
+    let nested_string = MessyJson::from(MessyJsonInner::String(MessyJsonScalar::new(false)));
+    MessyJson::from(MessyJsonInner::Obj(MessyJsonObject::from(
+        MessyJsonObjectInner::new(
+            vec![(gen_key("hello"), nested_string)]
+                .into_iter()
+                .collect(),
+            false,
+        ),
+    )))
+}
+
+const VAL: &str = r#"{ "hello": "a" }
+{ "hello": 1 }
+
+{ "hello": "c" }
+{ "hello": "d" } {}
+{ "hello": "e" }
+"#;
+
+#[test]
+fn reader() {
+    let parser = gen_parser();
+    let mut lines = parser.parse_lines(VAL.as_bytes(), MessyJsonSettings::default());
+
+    assert_eq!(
+        lines.next().unwrap().unwrap().inner()["hello"].as_str(),
+        Some("a")
+    );
+    let err = lines.next().unwrap().unwrap_err();
+    assert_eq!(err.line(), 2);
+    assert_eq!(err.to_string().starts_with("Line 2:"), true);
+    assert_eq!(lines.next().is_none(), true);
+}
+
+#[test]
+fn skip_invalid() {
+    let parser = gen_parser();
+    let lines: Vec<_> = parser
+        .parse_lines(VAL.as_bytes(), MessyJsonSettings::default())
+        .skip_invalid()
+        .collect();
+
+    assert_eq!(
+        lines
+            .iter()
+            .filter_map(|x| x.as_ref().ok())
+            .map(|x| x.inner()["hello"].as_str().unwrap())
+            .collect::<Vec<_>>(),
+        vec!["a", "c", "e"]
+    );
+    assert_eq!(
+        lines
+            .iter()
+            .filter_map(|x| x.as_ref().err())
+            .map(MessyJsonLinesError::line)
+            .collect::<Vec<_>>(),
+        vec![2, 5]
+    );
+}
+
+#[test]
+fn invalid_utf8() {
+    let parser = gen_parser();
+    let mut input: Vec<u8> = b"{ \"hello\": \"a\" }\n{ \"hello\": \"".to_vec();
+    input.extend_from_slice(&[0xff, 0xfe]);
+    input.extend_from_slice(b"\" }\n{ \"hello\": \"c\" }\n");
+    let mut lines = parser
+        .parse_lines(input.as_slice(), MessyJsonSettings::default())
+        .skip_invalid();
+
+    assert_eq!(
+        lines.next().unwrap().unwrap().inner()["hello"].as_str(),
+        Some("a")
+    );
+    assert_eq!(
+        matches!(
+            lines.next(),
+            Some(Err(MessyJsonLinesError::Invalid { line: 2, .. }))
+        ),
+        true
+    );
+    assert_eq!(
+        lines.next().unwrap().unwrap().inner()["hello"].as_str(),
+        Some("c")
+    );
+    assert_eq!(lines.next().is_none(), true);
+}
+
+#[test]
+fn string() {
+    let parser = gen_parser();
+    let mut lines = parser
+        .parse_lines_str(VAL, MessyJsonSettings::default())
+        .skip_invalid();
+    let first = lines.next().unwrap().unwrap();
+
+    assert_eq!(
+        matches!(
+            first.inner()["hello"],
+            MessyJsonValue::String(Cow::Borrowed("a"))
+        ),
+        true
+    );
+    assert_eq!(lines.filter(Result::is_ok).count(), 2);
+}
+
+#[test]
+fn io_error() {
+    struct Failing;
+
+    impl std::io::Read for Failing {
+        fn read(&mut self, _buf: &mut [u8]) -> std::io::Result<usize> {
+            Err(std::io::Error::other("failing"))
+        }
+    }
+    let parser = gen_parser();
+    let mut lines = parser
+        .parse_lines(
+            std::io::BufReader::new(Failing),
+            MessyJsonSettings::default(),
+        )
+        .skip_invalid();
+
+    assert_eq!(
+        matches!(
+            lines.next(),
+            Some(Err(MessyJsonLinesError::Io { line: 1, .. }))
+        ),
+        true
+    );
+    assert_eq!(lines.next().is_none(), true);
+}
@@ -0,0 +1,157 @@
+use super::*;
+
+fn gen_parser() -> MessyJson {
+    let nested_string = MessyJson::from(MessyJsonInner::String(MessyJsonScalar::new(false)));
+    let nested_opt = MessyJson::from(MessyJsonInner::String(MessyJsonScalar::new(true)));
+    let nested_obj = MessyJson::from(MessyJsonInner::Obj(MessyJsonObject::from(
+        MessyJsonObjectInner::new(
+            vec![
+                (gen_key("city"), nested_string.clone()),
+                (gen_key("street"), nested_opt.clone()),
+            ]
+            .into_iter()
+            .collect(),
+            false,
+        ),
+    )));
+    MessyJson::from(MessyJsonInner::Obj(MessyJsonObject::from(
+        MessyJsonObjectInner::new(
+            vec![
+                (gen_key("name"), nested_string),
+                (gen_key("nickname"), nested_opt),
+                (gen_key("address"), nested_obj),
+            ]
+            .into_iter()
+            .collect(),
+            false,
+        ),
+    )))
+}
+
+fn parse<'a>(
+    parser: &MessyJson,
+    value: &'a str,
+    settings: MessyJsonSettings,
+) -> MessyJsonValue<'a> {
+    let mut deserializer = serde_json::Deserializer::from_str(value);
+    parser
+        .builder(settings)
+        .deserialize(&mut deserializer)
+        .unwrap()
+        .take()
+}
+
+const TARGET: &str = r#"
+{
+	"name": "Jean",
+	"nickname": "JJ",
+	"address": {
+		"city": "Paris",
+		"street": "Rue de Rivoli"
+	}
+}
+"#;
+
+fn patch_settings() -> MessyJsonSettings {
+    MessyJsonSettings {
+        all_optional: true,
+        preserve_mandatory: true,
+        ..MessyJsonSettings::default()
+    }
+}
+
+#[test]
+fn merge() {
+    let parser = gen_parser();
+    let mut target = parse(&parser, TARGET, MessyJsonSettings::default());
+    let patch = parse(
+        &parser,
+        r#"{ "nickname": null, "address": { "city": "Lyon" } }"#,
+        patch_settings(),
+    );
+
+    parser
+        .edit(&mut target, MessyJsonSettings::default())
+        .apply_merge_patch(&patch)
+        .unwrap();
+    assert_eq!(
+        target,
+        serde_json::json!({
+            "name": "Jean",
+            "address": {
+                "city": "Lyon",
+                "street": "Rue de Rivoli"
+            }
+        })
+    );
+    assert_eq!(target["nickname"].is_absent(), true);
+}
+
+#[test]
+fn absent_untouched() {
+    let parser = gen_parser();
+    let mut target = parse(&parser, TARGET, MessyJsonSettings::default());
+    let mut patch = parse(
+        &parser,
+        r#"{ "address": { "city": "Lyon" } }"#,
+        patch_settings(),
+    );
+    patch.as_object_mut().unwrap().insert(
+        gen_key("nickname"),
+        MessyJsonValue::Null(
+            MessyJsonNullType::Absent,
+            MessyJsonExpected::Root(parser.clone()),
+        ),
+    );
+
+    parser
+        .edit(&mut target, MessyJsonSettings::default())
+        .apply_merge_patch(&patch)
+        .unwrap();
+    assert_eq!(target["nickname"].as_str(), Some("JJ"));
+    assert_eq!(
+        target
+            .pointer("/address/street")
+            .and_then(MessyJsonValue::as_str),
+        Some("Rue de Rivoli")
+    );
+}
+
+#[test]
+fn mandatory_null() {
+    let parser = gen_parser();
+    let mut target = parse(&parser, TARGET, MessyJsonSettings::default());
+    let expected = target.clone();
+    let patch = parse(
+        &parser,
+        r#"{ "name": null }"#,
+        MessyJsonSettings {
+            all_optional: true,
+            preserve_mandatory: false,
+            ..MessyJsonSettings::default()
+        },
+    );
+
+    parser
+        .edit(&mut target, MessyJsonSettings::default())
+        .apply_merge_patch(&patch)
+        .unwrap_err();
+    parser
+        .edit(&mut target, patch_settings())
+        .apply_merge_patch(&patch)
+        .unwrap_err();
+    assert_eq!(target, expected, "The target should be untouched");
+
+    target.apply_merge_patch(&patch);
+    assert_eq!(target["name"].is_absent(), true);
+}
+
+#[test]
+fn replace_non_object() {
+    let parser = gen_parser();
+    let mut target = MessyJsonValue::Bool(true);
+    let patch = parse(&parser, TARGET, MessyJsonSettings::default());
+
+    target.apply_merge_patch(&patch);
+    assert_eq!(target, patch);
+}
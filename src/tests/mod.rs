@@ -1,14 +1,39 @@
+#![allow(clippy::bool_assert_comparison)]
 use super::*;
 use serde::de::DeserializeSeed;
 
+mod access;
+mod aliases;
 mod all_optional;
 mod cmp_value;
+mod coercion;
+mod convert;
+mod diff;
+mod duplicate_keys;
+mod limits;
+mod lines;
+mod merge_patch;
 mod null_vs_absent;
+mod overrides;
+mod owned;
 mod parse_array_object;
 mod parse_nested_object;
 mod parse_simple;
+mod patch;
+mod projection;
+mod query;
+mod read_write;
 mod root_array;
+mod stream;
+mod transform;
 mod unexact_obj;
+mod value_mut;
 
+#[cfg(feature = "bumpalo")]
+mod arena;
+#[cfg(feature = "tokio")]
+mod async_read;
+#[cfg(feature = "preserve_order")]
+mod preserve_order;
 #[cfg(feature = "uuid")]
 mod uuid;
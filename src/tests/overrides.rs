@@ -0,0 +1,212 @@
+use super::*;
+
+fn gen_parser() -> MessyJson {
+    let nested_string = MessyJson::from(MessyJsonInner::String(MessyJsonScalar::new(false)));
+    let nested_address = MessyJson::from(MessyJsonInner::Obj(MessyJsonObject::from(
+        MessyJsonObjectInner::new(
+            vec![
+                (gen_key("city"), nested_string.clone()),
+                (gen_key("street"), nested_string.clone()),
+            ]
+            .into_iter()
+            .collect(),
+            false,
+        ),
+    )));
+    let nested_addresses = MessyJson::from(MessyJsonInner::Array(MessyJsonArray::new(
+        nested_address.clone(),
+        false,
+    )));
+    MessyJson::from(MessyJsonInner::Obj(MessyJsonObject::from(
+        MessyJsonObjectInner::new(
+            vec![
+                (gen_key("name"), nested_string.clone()),
+                (gen_key("surname"), nested_string),
+                (gen_key("address"), nested_address),
+                (gen_key("previous_addresses"), nested_addresses),
+            ]
+            .into_iter()
+            .collect(),
+            false,
+        ),
+    )))
+}
+
+fn partial() -> MessyJsonSettings {
+    MessyJsonSettings {
+        all_optional: true,
+        ..MessyJsonSettings::default()
+    }
+}
+
+/// Override that makes the keys mandatory again
+fn complete() -> MessyJsonPartialSettings {
+    MessyJsonPartialSettings {
+        all_optional: Some(false),
+        ..MessyJsonPartialSettings::default()
+    }
+}
+
+/// Override that makes every key optional
+fn optional() -> MessyJsonPartialSettings {
+    MessyJsonPartialSettings {
+        all_optional: Some(true),
+        ..MessyJsonPartialSettings::default()
+    }
+}
+
+fn parse(
+    parser: &MessyJson,
+    value: &str,
+    settings: MessyJsonSettings,
+    overrides: MessyJsonSettingsOverrides,
+) -> Result<(), serde_json::Error> {
+    let mut deserializer = serde_json::Deserializer::from_str(value);
+    parser
+        .builder(settings)
+        .with_overrides(overrides)
+        .deserialize(&mut deserializer)
+        .map(|_| ())
+}
+
+#[test]
+fn complete_nested_object() {
+    let parser = gen_parser();
+    let overrides = MessyJsonSettingsOverrides::new()
+        .with_pointer("/address", complete())
+        .unwrap();
+
+    parse(
+        &parser,
+        r#"{ "name": "Jean" }"#,
+        partial(),
+        overrides.clone(),
+    )
+    .unwrap();
+    parse(
+        &parser,
+        r#"{ "name": "Jean", "address": { "city": "Paris", "street": "Rue de Rivoli" } }"#,
+        partial(),
+        overrides.clone(),
+    )
+    .unwrap();
+    parse(
+        &parser,
+        r#"{ "name": "Jean", "address": { "city": "Paris" } }"#,
+        partial(),
+        overrides,
+    )
+    .unwrap_err();
+}
+
+#[test]
+fn inheritance() {
+    let parser = gen_parser();
+    let overrides = MessyJsonSettingsOverrides::new()
+        .with_settings(complete())
+        .with_field(
+            "previous_addresses",
+            MessyJsonSettingsOverrides::new().with_settings(optional()),
+        );
+    const VAL: &str = r#"
+	{
+		"name": "Jean",
+		"surname": "Dupont",
+		"address": { "city": "Paris", "street": "Rue de Rivoli" },
+		"previous_addresses": [{ "city": "Lyon" }]
+	}
+	"#;
+
+    parse(&parser, VAL, partial(), overrides.clone()).unwrap();
+    parse(
+        &parser,
+        VAL,
+        partial(),
+        MessyJsonSettingsOverrides::new()
+            .with_pointer("", complete())
+            .unwrap(),
+    )
+    .unwrap_err();
+    parse(
+        &parser,
+        r#"{ "name": "Jean", "previous_addresses": [] }"#,
+        partial(),
+        overrides,
+    )
+    .unwrap_err();
+}
+
+#[test]
+fn invalid_pointer() {
+    assert_eq!(
+        MessyJsonSettingsOverrides::new().with_pointer("address", optional()),
+        Err(MessyJsonError::InvalidPointer("address".to_string()))
+    );
+}
+
+#[test]
+fn inherited_settings() {
+    let parser = gen_parser();
+    let settings = MessyJsonSettings {
+        all_optional: true,
+        ignore_unknown_keys: true,
+        limits: MessyJsonLimits {
+            max_string_length: Some(8),
+            ..MessyJsonLimits::default()
+        },
+        ..MessyJsonSettings::default()
+    };
+    let overrides = MessyJsonSettingsOverrides::new()
+        .with_pointer("/address", complete())
+        .unwrap();
+
+    parse(
+        &parser,
+        r#"{ "address": { "city": "Paris", "street": "Rivoli", "zip": 1 } }"#,
+        settings,
+        overrides.clone(),
+    )
+    .unwrap();
+    let err = parse(
+        &parser,
+        r#"{ "address": { "city": "Paris", "street": "Rue de Rivoli" } }"#,
+        settings,
+        overrides,
+    )
+    .unwrap_err();
+    assert_eq!(err.to_string().contains("Limit exceeded"), true);
+}
+
+#[test]
+fn full_replacement() {
+    let parser = gen_parser();
+    let settings = MessyJsonSettings {
+        all_optional: true,
+        ignore_unknown_keys: true,
+        ..MessyJsonSettings::default()
+    };
+    let overrides = MessyJsonSettingsOverrides::new()
+        .with_pointer(
+            "/address",
+            MessyJsonPartialSettings::from(MessyJsonSettings {
+                all_optional: true,
+                ..MessyJsonSettings::default()
+            }),
+        )
+        .unwrap();
+
+    parse(
+        &parser,
+        r#"{ "zip": 1, "address": {} }"#,
+        settings,
+        overrides.clone(),
+    )
+    .unwrap();
+    parse(
+        &parser,
+        r#"{ "address": { "zip": 1 } }"#,
+        settings,
+        overrides,
+    )
+    .unwrap_err();
+}
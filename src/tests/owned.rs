@@ -0,0 +1,109 @@
+use super::*;
+
+fn gen_parser() -> MessyJson {
+    let nested_string = MessyJson::from(MessyJsonInner::String(MessyJsonScalar::new(false)));
+    let nested_array = MessyJson::from(MessyJsonInner::Array(MessyJsonArray::new(
+        nested_string.clone(),
+        false,
+    )));
+    let nested_opt = MessyJson::from(MessyJsonInner::Bool(MessyJsonScalar::new(true)));
+    MessyJson::from(MessyJsonInner::Obj(MessyJsonObject::from(
+        MessyJsonObjectInner::new(
+            vec![
+                (gen_key("hello"), nested_string),
+                (gen_key("array"), nested_array),
+                (gen_key("opt"), nested_opt),
+            ]
+            .into_iter()
+            .collect(),
+            false,
+        ),
+    )))
+}
+
+/// Check that no string of a value is borrowed
+fn all_owned(value: &MessyJsonValue) -> bool {
+    match value {
+        MessyJsonValue::String(Cow::Borrowed(_)) => false,
+        MessyJsonValue::Array(arr) => arr.iter().all(all_owned),
+        MessyJsonValue::Obj(obj) => obj.values().all(all_owned),
+        _ => true,
+    }
+}
+
+fn parse_owned(parser: &MessyJson) -> MessyJsonValueContainer<'static> {
+    let buf = String::from(r#"{ "hello": "world", "array": ["a", "b"] }"#);
+    let mut deserializer = serde_json::Deserializer::from_str(&buf);
+    let parsed = parser
+        .builder(MessyJsonSettings::default())
+        .deserialize(&mut deserializer)
+        .unwrap();
+
+    assert_eq!(all_owned(parsed.inner()), false);
+    parsed.into_owned()
+}
+
+#[test]
+fn value() {
+    let parser = gen_parser();
+    let parsed = parse_owned(&parser);
+
+    assert_eq!(all_owned(parsed.inner()), true);
+    assert_eq!(
+        parsed.inner(),
+        &serde_json::json!({
+            "hello": "world",
+            "array": ["a", "b"]
+        })
+    );
+    assert_eq!(
+        matches!(
+            parsed.inner()["opt"],
+            MessyJsonValue::Null(MessyJsonNullType::Absent, _)
+        ),
+        true
+    );
+}
+
+#[test]
+fn to_owned() {
+    let parser = gen_parser();
+    let buf = String::from(r#"{ "hello": "world", "array": [] }"#);
+    let raw: MessyJsonValueRaw = serde_json::from_str(&buf).unwrap();
+    let parsed = parser
+        .validate_raw(&raw, MessyJsonSettings::default())
+        .unwrap();
+    let owned: MessyJsonValue<'static> = parsed.to_owned();
+    let owned_raw: MessyJsonValueRaw<'static> = raw.to_owned();
+
+    assert_eq!(owned, parsed);
+    assert_eq!(owned_raw, raw);
+    drop(parsed);
+    drop(raw);
+    drop(buf);
+    assert_eq!(all_owned(&owned), true);
+    assert_eq!(owned_raw["hello"].as_str(), Some("world"));
+}
+
+#[test]
+fn raw() {
+    let raw: MessyJsonValueRaw<'static> = {
+        let buf = String::from(r#"{ "hello": { "nested": ["world"] } }"#);
+        let raw: MessyJsonValueRaw = serde_json::from_str(&buf).unwrap();
+        raw.into_owned()
+    };
+
+    match &raw {
+        MessyJsonValueRaw::Obj(obj) => {
+            assert_eq!(obj.keys().all(|x| matches!(x, Cow::Owned(_))), true);
+        }
+        _ => panic!("should've been an object"),
+    }
+    assert_eq!(
+        matches!(
+            raw.pointer("/hello/nested/0"),
+            Some(MessyJsonValueRaw::String(Cow::Owned(x))) if x == "world"
+        ),
+        true
+    );
+}
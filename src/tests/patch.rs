@@ -0,0 +1,217 @@
+use super::*;
+
+fn gen_parser() -> MessyJson {
+    let nested_string = MessyJson::from(MessyJsonInner::String(MessyJsonScalar::new(false)));
+    let nested_opt = MessyJson::from(MessyJsonInner::String(MessyJsonScalar::new(true)));
+    let nested_number = MessyJson::from(MessyJsonInner::Number(MessyJsonNumeric::new(
+        MessyJsonNumberType::U64,
+        false,
+    )));
+    let nested_array = MessyJson::from(MessyJsonInner::Array(MessyJsonArray::new(
+        nested_number,
+        false,
+    )));
+    MessyJson::from(MessyJsonInner::Obj(MessyJsonObject::from(
+        MessyJsonObjectInner::new(
+            vec![
+                (gen_key("name"), nested_string),
+                (gen_key("nickname"), nested_opt.clone()),
+                (gen_key("alias"), nested_opt),
+                (gen_key("numbers"), nested_array),
+            ]
+            .into_iter()
+            .collect(),
+            false,
+        ),
+    )))
+}
+
+fn gen_value(parser: &MessyJson) -> MessyJsonValue<'static> {
+    const VAL: &str = r#"
+	{
+		"name": "Jean",
+		"nickname": "JJ",
+		"numbers": [1, 2, 3]
+	}
+	"#;
+    let mut deserializer = serde_json::Deserializer::from_str(VAL);
+    parser
+        .builder(MessyJsonSettings::default())
+        .deserialize(&mut deserializer)
+        .unwrap()
+        .take()
+}
+
+fn parse_patch<'a>(parser: &MessyJson, patch: &'a str) -> Vec<MessyJsonPatchOperation<'a>> {
+    let raw: MessyJsonValueRaw = serde_json::from_str(patch).unwrap();
+    parser
+        .parse_patch(raw, MessyJsonSettings::default())
+        .unwrap()
+}
+
+#[test]
+fn operations() {
+    let parser = gen_parser();
+    let mut value = gen_value(&parser);
+    let patch = parse_patch(
+        &parser,
+        r#"[
+			{ "op": "test", "path": "/name", "value": "Jean" },
+			{ "op": "replace", "path": "/name", "value": "Jacques" },
+			{ "op": "move", "from": "/nickname", "path": "/alias" },
+			{ "op": "add", "path": "/numbers/1", "value": 4 },
+			{ "op": "add", "path": "/numbers/-", "value": 5 },
+			{ "op": "remove", "path": "/numbers/0" },
+			{ "op": "copy", "from": "/numbers/0", "path": "/numbers/0" }
+		]"#,
+    );
+
+    parser
+        .edit(&mut value, MessyJsonSettings::default())
+        .apply_patch(&patch)
+        .unwrap();
+    assert_eq!(
+        value,
+        serde_json::json!({
+            "name": "Jacques",
+            "alias": "JJ",
+            "numbers": [4, 4, 2, 3, 5]
+        })
+    );
+}
+
+#[test]
+fn removed_keys() {
+    let parser = gen_parser();
+    let mut value = gen_value(&parser);
+    let patch = parse_patch(
+        &parser,
+        r#"[
+			{ "op": "move", "from": "/nickname", "path": "/alias" },
+			{ "op": "remove", "path": "/alias" }
+		]"#,
+    );
+    let mut deserializer =
+        serde_json::Deserializer::from_str(r#"{ "name": "Jean", "numbers": [1, 2, 3] }"#);
+    let expected = parser
+        .builder(MessyJsonSettings::default())
+        .deserialize(&mut deserializer)
+        .unwrap()
+        .take();
+
+    parser
+        .edit(&mut value, MessyJsonSettings::default())
+        .apply_patch(&patch)
+        .unwrap();
+    assert_eq!(value["nickname"].is_absent(), true);
+    assert_eq!(value["alias"].is_absent(), true);
+    assert_eq!(value, expected);
+
+    // Without a schema, the removed keys are dropped from their object
+    let mut value = gen_value(&parser);
+    value.apply_patch(&patch).unwrap();
+    assert_eq!(value.get("nickname").is_none(), true);
+    assert_eq!(value.get("alias").is_none(), true);
+}
+
+#[test]
+fn invalid_value() {
+    let parser = gen_parser();
+    let raw: MessyJsonValueRaw =
+        serde_json::from_str(r#"[{ "op": "add", "path": "/numbers/0", "value": "1" }]"#).unwrap();
+    assert_eq!(
+        matches!(
+            parser.parse_patch(raw, MessyJsonSettings::default()),
+            Err(MessyJsonError::Validation(_))
+        ),
+        true
+    );
+    let raw: MessyJsonValueRaw =
+        serde_json::from_str(r#"[{ "op": "add", "path": "/unknown", "value": "1" }]"#).unwrap();
+    assert_eq!(
+        matches!(
+            parser.parse_patch(raw, MessyJsonSettings::default()),
+            Err(MessyJsonError::InvalidPointer(_))
+        ),
+        true
+    );
+    let raw: MessyJsonValueRaw = serde_json::from_str(r#"[{ "op": "nope", "path": "" }]"#).unwrap();
+    assert_eq!(
+        matches!(
+            parser.parse_patch(raw, MessyJsonSettings::default()),
+            Err(MessyJsonError::InvalidPatch(_))
+        ),
+        true
+    );
+}
+
+#[test]
+fn schema_violation() {
+    let parser = gen_parser();
+    let mut value = gen_value(&parser);
+    let patch = parse_patch(
+        &parser,
+        r#"[
+			{ "op": "replace", "path": "/nickname", "value": "Jojo" },
+			{ "op": "remove", "path": "/name" }
+		]"#,
+    );
+
+    assert_eq!(
+        matches!(
+            parser
+                .edit(&mut value, MessyJsonSettings::default())
+                .apply_patch(&patch),
+            Err(MessyJsonError::Validation(_))
+        ),
+        true
+    );
+    assert_eq!(value, gen_value(&parser));
+}
+
+#[test]
+fn failures() {
+    let parser = gen_parser();
+    let mut value = gen_value(&parser);
+    let patch = parse_patch(
+        &parser,
+        r#"[
+			{ "op": "replace", "path": "/nickname", "value": "Jojo" },
+			{ "op": "test", "path": "/name", "value": "Jacques" }
+		]"#,
+    );
+
+    assert_eq!(
+        parser
+            .edit(&mut value, MessyJsonSettings::default())
+            .apply_patch(&patch),
+        Err(MessyJsonError::TestFailed("/name".to_string()))
+    );
+    assert_eq!(value["nickname"].as_str(), Some("JJ"));
+    for patch in [
+        r#"[{ "op": "remove", "path": "/alias" }]"#,
+        r#"[{ "op": "replace", "path": "/alias", "value": "J" }]"#,
+        r#"[{ "op": "add", "path": "/numbers/4", "value": 1 }]"#,
+        r#"[{ "op": "copy", "from": "/numbers/01", "path": "/numbers/0" }]"#,
+    ]
+    .iter()
+    {
+        assert_eq!(
+            matches!(
+                value.apply_patch(&parse_patch(&parser, patch)),
+                Err(MessyJsonError::InvalidPointer(_))
+            ),
+            true
+        );
+    }
+    assert_eq!(
+        matches!(
+            value.apply_patch(&[MessyJsonPatchOperation::Move {
+                from: "/numbers".to_string(),
+                path: "/numbers/0".to_string()
+            }]),
+            Err(MessyJsonError::InvalidPatch(_))
+        ),
+        true
+    );
+}
@@ -0,0 +1,102 @@
+use super::*;
+
+fn gen_parser() -> MessyJson {
+    let nested_string = MessyJson::from(MessyJsonInner::String(MessyJsonScalar::new(false)));
+    let nested_obj = MessyJson::from(MessyJsonInner::Obj(MessyJsonObject::from(
+        MessyJsonObjectInner::new(
+            vec![
+                (gen_key("b"), nested_string.clone()),
+                (gen_key("a"), nested_string.clone()),
+            ]
+            .into_iter()
+            .collect(),
+            false,
+        ),
+    )));
+    MessyJson::from(MessyJsonInner::Obj(MessyJsonObject::from(
+        MessyJsonObjectInner::new(
+            vec![
+                (gen_key("zeta"), nested_string.clone()),
+                (gen_key("alpha"), nested_obj),
+                (gen_key("mu"), nested_string),
+            ]
+            .into_iter()
+            .collect(),
+            false,
+        ),
+    )))
+}
+
+const VAL: &str = r#"{"zeta":"z","alpha":{"b":"b","a":"a"},"mu":"m"}"#;
+
+#[test]
+fn parse_and_serialize() {
+    let parser = gen_parser();
+    let mut deserializer = serde_json::Deserializer::from_str(VAL);
+    let parsed = parser
+        .builder(MessyJsonSettings::default())
+        .deserialize(&mut deserializer)
+        .unwrap();
+
+    assert_eq!(
+        parsed
+            .inner()
+            .as_object()
+            .unwrap()
+            .keys()
+            .map(ArcStr::as_str)
+            .collect::<Vec<_>>(),
+        vec!["zeta", "alpha", "mu"]
+    );
+    assert_eq!(
+        serde_json::to_string(&parser.serializer(parsed.inner(), MessyJsonSettings::default()))
+            .unwrap(),
+        VAL
+    );
+}
+
+#[test]
+fn raw() {
+    let raw: MessyJsonValueRaw = serde_json::from_str(VAL).unwrap();
+
+    assert_eq!(serde_json::to_string(&raw).unwrap(), VAL);
+    let parsed = gen_parser()
+        .validate_raw(&raw, MessyJsonSettings::default())
+        .unwrap();
+    assert_eq!(
+        parsed["alpha"]
+            .as_object()
+            .unwrap()
+            .keys()
+            .map(ArcStr::as_str)
+            .collect::<Vec<_>>(),
+        vec!["b", "a"]
+    );
+}
+
+#[test]
+fn remove_keeps_order() {
+    let mut raw: MessyJsonValueRaw = serde_json::from_str(VAL).unwrap();
+    let parser = gen_parser();
+    let mut parsed = parser
+        .validate_raw_owned(raw.clone(), MessyJsonSettings::default())
+        .unwrap();
+
+    parsed.as_object_mut().unwrap().remove("zeta");
+    assert_eq!(
+        parsed
+            .as_object()
+            .unwrap()
+            .keys()
+            .map(ArcStr::as_str)
+            .collect::<Vec<_>>(),
+        vec!["alpha", "mu"]
+    );
+    if let MessyJsonValueRaw::Obj(obj) = &mut raw {
+        obj.shift_remove("alpha");
+    }
+    assert_eq!(
+        serde_json::to_string(&raw).unwrap(),
+        r#"{"zeta":"z","mu":"m"}"#
+    );
+}
@@ -0,0 +1,205 @@
+use super::*;
+
+fn gen_parser() -> MessyJson {
+    let nested_string = MessyJson::from(MessyJsonInner::String(MessyJsonScalar::new(false)));
+    let nested_opt = MessyJson::from(MessyJsonInner::String(MessyJsonScalar::new(true)));
+    let nested_author = MessyJson::from(MessyJsonInner::Obj(MessyJsonObject::from(
+        MessyJsonObjectInner::new(
+            vec![
+                (gen_key("name"), nested_string.clone()),
+                (gen_key("email"), nested_string.clone()),
+            ]
+            .into_iter()
+            .collect(),
+            false,
+        ),
+    )));
+    let nested_comment = MessyJson::from(MessyJsonInner::Obj(MessyJsonObject::from(
+        MessyJsonObjectInner::new(
+            vec![
+                (gen_key("body"), nested_string.clone()),
+                (gen_key("author"), nested_string.clone()),
+            ]
+            .into_iter()
+            .collect(),
+            false,
+        ),
+    )));
+    let nested_comments = MessyJson::from(MessyJsonInner::Array(MessyJsonArray::new(
+        nested_comment,
+        false,
+    )));
+    MessyJson::from(MessyJsonInner::Obj(MessyJsonObject::from(
+        MessyJsonObjectInner::new(
+            vec![
+                (gen_key("title"), nested_string.clone()),
+                (gen_key("body"), nested_string),
+                (gen_key("subtitle"), nested_opt),
+                (gen_key("author"), nested_author),
+                (gen_key("comments"), nested_comments),
+            ]
+            .into_iter()
+            .collect(),
+            false,
+        ),
+    )))
+}
+
+const VAL: &str = r#"
+{
+	"title": "Hello",
+	"body": "World",
+	"author": {
+		"name": "Jean",
+		"email": "jean@example.com"
+	},
+	"comments": [
+		{ "body": "First", "author": "Jacques" },
+		{ "body": "Second", "author": "Jojo" }
+	]
+}
+"#;
+
+fn parse<'a>(
+    parser: &MessyJson,
+    value: &'a str,
+    projection: MessyJsonProjection,
+    mode: MessyJsonProjectionMode,
+) -> Result<MessyJsonValue<'a>, serde_json::Error> {
+    let mut deserializer = serde_json::Deserializer::from_str(value);
+    parser
+        .builder(MessyJsonSettings::default())
+        .with_projection(projection, mode)
+        .deserialize(&mut deserializer)
+        .map(MessyJsonValueContainer::take)
+}
+
+#[test]
+fn pointers() {
+    let parser = gen_parser();
+    let projection = MessyJsonProjection::from_pointers([
+        "/title",
+        "/subtitle",
+        "/author/name",
+        "/comments/body",
+    ])
+    .unwrap();
+    let parsed = parse(&parser, VAL, projection, MessyJsonProjectionMode::Skip).unwrap();
+
+    assert_eq!(
+        parsed,
+        serde_json::json!({
+            "title": "Hello",
+            "author": {
+                "name": "Jean"
+            },
+            "comments": [
+                { "body": "First" },
+                { "body": "Second" }
+            ]
+        })
+    );
+    assert_eq!(parsed["subtitle"].is_absent(), true);
+    assert_eq!(parsed.get("body").is_none(), true);
+}
+
+#[test]
+fn nested_key_set() {
+    let parser = gen_parser();
+    let projection = MessyJsonProjection::new()
+        .with_field("title", MessyJsonProjection::new())
+        .with_field(
+            "author",
+            MessyJsonProjection::new().with_field("email", MessyJsonProjection::new()),
+        );
+    let parsed = parse(&parser, VAL, projection, MessyJsonProjectionMode::Skip).unwrap();
+
+    assert_eq!(
+        parsed,
+        serde_json::json!({
+            "title": "Hello",
+            "author": {
+                "email": "jean@example.com"
+            }
+        })
+    );
+}
+
+#[test]
+fn whole_value() {
+    let projection =
+        MessyJsonProjection::from_pointers(["/author/name", "/author", "/comments/body"]).unwrap();
+
+    assert_eq!(projection.get("author").map(|x| x.is_empty()), Some(true));
+    assert_eq!(
+        projection
+            .get("comments")
+            .and_then(|x| x.get("body"))
+            .is_some(),
+        true
+    );
+    assert_eq!(
+        MessyJsonProjection::from_pointers(["/title", ""])
+            .unwrap()
+            .is_empty(),
+        true
+    );
+    assert_eq!(
+        matches!(
+            MessyJsonProjection::from_pointers(["title"]),
+            Err(MessyJsonError::InvalidPointer(_))
+        ),
+        true
+    );
+}
+
+#[test]
+fn skip_vs_validate() {
+    let parser = gen_parser();
+    const INVALID: &str = r#"
+	{
+		"title": "Hello",
+		"body": 1234,
+		"comments": []
+	}
+	"#;
+    let projection = MessyJsonProjection::from_pointers(["/title"]).unwrap();
+
+    let parsed = parse(
+        &parser,
+        INVALID,
+        projection.clone(),
+        MessyJsonProjectionMode::Skip,
+    )
+    .unwrap();
+    assert_eq!(parsed, serde_json::json!({ "title": "Hello" }));
+    parse(
+        &parser,
+        INVALID,
+        projection,
+        MessyJsonProjectionMode::Validate,
+    )
+    .unwrap_err();
+    let parsed = parse(
+        &parser,
+        VAL,
+        MessyJsonProjection::from_pointers(["/title"]).unwrap(),
+        MessyJsonProjectionMode::Validate,
+    )
+    .unwrap();
+    assert_eq!(parsed, serde_json::json!({ "title": "Hello" }));
+}
+
+#[test]
+fn missing_selected_key() {
+    let parser = gen_parser();
+    let projection = MessyJsonProjection::from_pointers(["/author"]).unwrap();
+
+    parse(
+        &parser,
+        r#"{ "title": "Hello" }"#,
+        projection,
+        MessyJsonProjectionMode::Skip,
+    )
+    .unwrap_err();
+}
@@ -0,0 +1,227 @@
+use super::*;
+use std::convert::TryFrom;
+
+fn gen_parser() -> MessyJson {
+    let string = MessyJson::from(MessyJsonInner::String(MessyJsonScalar::new(true)));
+    let number = MessyJson::from(MessyJsonInner::Number(MessyJsonNumeric::new(
+        MessyJsonNumberType::U64,
+        true,
+    )));
+    let page = MessyJson::from(MessyJsonInner::Obj(MessyJsonObject::from(
+        MessyJsonObjectInner::new(
+            vec![
+                (gen_key("size"), number.clone()),
+                (gen_key("number"), number.clone()),
+            ]
+            .into_iter()
+            .collect(),
+            true,
+        ),
+    )));
+    let author = MessyJson::from(MessyJsonInner::Obj(MessyJsonObject::from(
+        MessyJsonObjectInner::new(
+            vec![(gen_key("name"), string.clone())]
+                .into_iter()
+                .collect(),
+            true,
+        ),
+    )));
+    let filter = MessyJson::from(MessyJsonInner::Obj(MessyJsonObject::from(
+        MessyJsonObjectInner::new(
+            vec![
+                (gen_key("author"), author),
+                (
+                    gen_key("published"),
+                    MessyJson::from(MessyJsonInner::Bool(MessyJsonScalar::new(true))),
+                ),
+            ]
+            .into_iter()
+            .collect(),
+            true,
+        ),
+    )));
+    let ids = MessyJson::from(MessyJsonInner::Array(MessyJsonArray::new(number, true)));
+    MessyJson::from(MessyJsonInner::Obj(MessyJsonObject::from(
+        MessyJsonObjectInner::new(
+            vec![
+                (gen_key("page"), page),
+                (gen_key("filter"), filter),
+                (gen_key("ids"), ids),
+                (gen_key("sort"), string),
+            ]
+            .into_iter()
+            .collect(),
+            false,
+        ),
+    )))
+}
+
+#[test]
+fn simple() {
+    let parsed = gen_parser()
+        .parse_query(
+            "?page[size]=10&filter[author][name]=Jean+Dupont%21&filter[published]=true&ids[]=1&ids[]=2&sort=",
+            MessyJsonSettings::default(),
+        )
+        .unwrap();
+
+    assert_eq!(
+        parsed,
+        serde_json::json!({
+            "page": { "size": 10 },
+            "filter": {
+                "author": { "name": "Jean Dupont!" },
+                "published": true
+            },
+            "ids": [1, 2],
+            "sort": ""
+        })
+    );
+    assert_eq!(
+        matches!(&parsed["sort"], MessyJsonValue::String(Cow::Borrowed(_))),
+        true
+    );
+    assert_eq!(
+        matches!(
+            &parsed["filter"]["author"]["name"],
+            MessyJsonValue::String(Cow::Owned(_))
+        ),
+        true
+    );
+}
+
+#[test]
+fn raw() {
+    let raw = MessyJsonValueRaw::from_query("a%5Bb%5D=1&c[]=x&c[]=y", MessyJsonSettings::default())
+        .unwrap();
+
+    assert_eq!(
+        serde_json::Value::try_from(raw).unwrap(),
+        serde_json::json!({
+            "a": { "b": "1" },
+            "c": ["x", "y"]
+        })
+    );
+}
+
+#[test]
+fn duplicated() {
+    let parser = gen_parser();
+
+    parser
+        .parse_query("sort=a&sort=b", MessyJsonSettings::default())
+        .unwrap_err();
+    let parsed = parser
+        .parse_query(
+            "sort=a&sort=b",
+            MessyJsonSettings {
+                duplicate_keys: MessyJsonDuplicateKeys::LastWins,
+                ..MessyJsonSettings::default()
+            },
+        )
+        .unwrap();
+    assert_eq!(parsed["sort"].as_str(), Some("b"));
+}
+
+#[test]
+fn invalid() {
+    let parser = gen_parser();
+
+    for query in [
+        "page[size]=ten",
+        "unknown=1",
+        "page[size=1",
+        "[size]=1",
+        "page[size]x=1",
+        "sort=%zz",
+        "sort=%ff",
+        "page=1&page[size]=1",
+        "ids[]=1&ids[a]=1",
+    ]
+    .iter()
+    {
+        parser
+            .parse_query(query, MessyJsonSettings::default())
+            .unwrap_err();
+    }
+    assert_eq!(
+        matches!(
+            parser.parse_query("sort=%zz", MessyJsonSettings::default()),
+            Err(MessyJsonError::InvalidQuery(_))
+        ),
+        true
+    );
+    assert_eq!(
+        matches!(
+            parser.parse_query("page[size]=ten", MessyJsonSettings::default()),
+            Err(MessyJsonError::Validation(_))
+        ),
+        true
+    );
+}
+
+fn limited(limits: MessyJsonLimits) -> MessyJsonSettings {
+    MessyJsonSettings {
+        limits,
+        ..MessyJsonSettings::default()
+    }
+}
+
+#[test]
+fn deep_key() {
+    let deep = |depth: usize| format!("a{}=1", "[a]".repeat(depth));
+    let max_depth = |max_depth| MessyJsonLimits {
+        max_depth: Some(max_depth),
+        ..MessyJsonLimits::default()
+    };
+
+    MessyJsonValueRaw::from_query(&deep(7), limited(max_depth(8))).unwrap();
+    let err = MessyJsonValueRaw::from_query(&deep(100), limited(max_depth(8))).unwrap_err();
+    assert_eq!(
+        MessyJsonLimitExceeded::from_error(&err),
+        Some(MessyJsonLimitExceeded::Depth(8))
+    );
+    // The nesting is bounded even without limits
+    MessyJsonValueRaw::from_query(&deep(127), MessyJsonSettings::default()).unwrap();
+    let err =
+        MessyJsonValueRaw::from_query(&deep(200_000), MessyJsonSettings::default()).unwrap_err();
+    assert_eq!(
+        MessyJsonLimitExceeded::from_error(&err),
+        Some(MessyJsonLimitExceeded::Depth(128))
+    );
+}
+
+#[test]
+fn limits() {
+    let keys = limited(MessyJsonLimits {
+        max_keys: Some(2),
+        ..MessyJsonLimits::default()
+    });
+    MessyJsonValueRaw::from_query("a=1&b[c]=2", keys).unwrap();
+    let err = MessyJsonValueRaw::from_query("a=1&b[c]=2&d[]=3", keys).unwrap_err();
+    assert_eq!(
+        MessyJsonLimitExceeded::from_error(&err),
+        Some(MessyJsonLimitExceeded::Keys(2))
+    );
+    MessyJsonValueRaw::from_query("a[b]=1&a[c]=2&a[d]=3", keys).unwrap_err();
+
+    let array_length = limited(MessyJsonLimits {
+        max_array_length: Some(2),
+        ..MessyJsonLimits::default()
+    });
+    MessyJsonValueRaw::from_query("a[]=1&a[]=2", array_length).unwrap();
+    MessyJsonValueRaw::from_query("a[]=1&a[]=2&a[]=3", array_length).unwrap_err();
+
+    let string_length = limited(MessyJsonLimits {
+        max_string_length: Some(3),
+        ..MessyJsonLimits::default()
+    });
+    MessyJsonValueRaw::from_query("abc[def]=ghi", string_length).unwrap();
+    for query in ["abcd=1", "a[bcde]=1", "a=bcde", "a=%20%20%20%20"].iter() {
+        let err = MessyJsonValueRaw::from_query(query, string_length).unwrap_err();
+        assert_eq!(
+            MessyJsonLimitExceeded::from_error(&err),
+            Some(MessyJsonLimitExceeded::StringLength(3))
+        );
+    }
+}
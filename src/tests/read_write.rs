@@ -0,0 +1,187 @@
+use super::*;
+
+fn gen_parser() -> MessyJson {
+    let nested_string = MessyJson::from(MessyJsonInner::String(MessyJsonScalar::new(false)));
+    let nested_opt = MessyJson::from(MessyJsonInner::String(MessyJsonScalar::new(true)));
+    MessyJson::from(MessyJsonInner::Obj(MessyJsonObject::from(
+        MessyJsonObjectInner::new(
+            vec![
+                (gen_key("id"), nested_string.clone()),
+                (gen_key("name"), nested_string.clone()),
+                (gen_key("nickname"), nested_opt),
+                (gen_key("password"), nested_string),
+            ]
+            .into_iter()
+            .collect(),
+            false,
+        )
+        .with_access("id", MessyJsonFieldAccess::ReadOnly)
+        .with_access("password", MessyJsonFieldAccess::WriteOnly),
+    )))
+}
+
+fn settings(direction: MessyJsonDirection) -> MessyJsonSettings {
+    MessyJsonSettings {
+        direction,
+        ..MessyJsonSettings::default()
+    }
+}
+
+fn parse<'a>(
+    parser: &MessyJson,
+    value: &'a str,
+    direction: MessyJsonDirection,
+) -> Result<MessyJsonValue<'a>, serde_json::Error> {
+    let mut deserializer = serde_json::Deserializer::from_str(value);
+    parser
+        .builder(settings(direction))
+        .deserialize(&mut deserializer)
+        .map(MessyJsonValueContainer::take)
+}
+
+#[test]
+fn input() {
+    let parser = gen_parser();
+
+    let parsed = parse(
+        &parser,
+        r#"{ "name": "Jean", "password": "1234" }"#,
+        MessyJsonDirection::Input,
+    )
+    .unwrap();
+    assert_eq!(parsed["id"].is_absent(), true);
+    parser
+        .validate(&parsed, settings(MessyJsonDirection::Input))
+        .unwrap();
+    parser
+        .validate(&parsed, settings(MessyJsonDirection::Any))
+        .unwrap_err();
+    let err = parse(
+        &parser,
+        r#"{ "id": "1", "name": "Jean", "password": "1234" }"#,
+        MessyJsonDirection::Input,
+    )
+    .unwrap_err();
+    assert_eq!(err.to_string().contains("read-only"), true);
+    parse(
+        &parser,
+        r#"{ "id": "1", "name": "Jean", "password": "1234" }"#,
+        MessyJsonDirection::Any,
+    )
+    .unwrap();
+}
+
+#[test]
+fn output() {
+    let parser = gen_parser();
+    let parsed = parse(
+        &parser,
+        r#"{ "id": "1", "name": "Jean", "password": "1234" }"#,
+        MessyJsonDirection::Any,
+    )
+    .unwrap();
+
+    assert_eq!(
+        serde_json::to_string(&parser.serializer(&parsed, settings(MessyJsonDirection::Output)))
+            .unwrap(),
+        r#"{"id":"1","name":"Jean"}"#
+    );
+    assert_eq!(
+        serde_json::to_string(&parser.serializer(&parsed, settings(MessyJsonDirection::Any)))
+            .unwrap(),
+        r#"{"id":"1","name":"Jean","password":"1234"}"#
+    );
+    parse(
+        &parser,
+        r#"{ "id": "1", "name": "Jean", "password": "1234" }"#,
+        MessyJsonDirection::Output,
+    )
+    .unwrap_err();
+}
+
+#[test]
+fn raw() {
+    let parser = gen_parser();
+    let raw: MessyJsonValueRaw =
+        serde_json::from_str(r#"{ "id": "1", "name": "Jean", "password": "1234" }"#).unwrap();
+
+    parser
+        .validate_raw(&raw, settings(MessyJsonDirection::Input))
+        .unwrap_err();
+    parser
+        .validate_raw(&raw, settings(MessyJsonDirection::Any))
+        .unwrap();
+}
+
+#[test]
+fn derivations() {
+    let parser = gen_parser();
+    let obj = match parser.deref() {
+        MessyJsonInner::Obj(x) => x.clone(),
+        _ => unreachable!(),
+    };
+    let conflicting = MessyJsonObject::from(
+        MessyJsonObjectInner::new(
+            vec![(
+                gen_key("id"),
+                MessyJson::from(MessyJsonInner::String(MessyJsonScalar::new(false))),
+            )]
+            .into_iter()
+            .collect(),
+            false,
+        )
+        .with_access("id", MessyJsonFieldAccess::WriteOnly),
+    );
+
+    assert_eq!(
+        obj.pick(["name", "password"]).access_restrictions().len(),
+        1
+    );
+    assert_eq!(
+        obj.omit(["id"]).access("password"),
+        MessyJsonFieldAccess::WriteOnly
+    );
+    assert_eq!(
+        obj.merge(&conflicting),
+        Err(MessyJsonError::SchemaConflict("/id".to_string()))
+    );
+    assert_eq!(
+        obj.extend(&conflicting).access("id"),
+        MessyJsonFieldAccess::WriteOnly
+    );
+}
+
+#[test]
+fn nested_conflict() {
+    let wrap = |obj: MessyJsonObject| {
+        MessyJson::from(MessyJsonInner::Obj(MessyJsonObject::from(
+            MessyJsonObjectInner::new(
+                vec![(gen_key("user"), MessyJson::from(MessyJsonInner::Obj(obj)))]
+                    .into_iter()
+                    .collect(),
+                false,
+            ),
+        )))
+    };
+    let obj = match gen_parser().deref() {
+        MessyJsonInner::Obj(x) => x.clone(),
+        _ => unreachable!(),
+    };
+    let conflicting = MessyJsonObject::from(
+        MessyJsonObjectInner::new(
+            vec![(
+                gen_key("id"),
+                MessyJson::from(MessyJsonInner::String(MessyJsonScalar::new(false))),
+            )]
+            .into_iter()
+            .collect(),
+            false,
+        )
+        .with_access("id", MessyJsonFieldAccess::WriteOnly),
+    );
+
+    assert_eq!(
+        wrap(obj).merge(&wrap(conflicting)),
+        Err(MessyJsonError::SchemaConflict("/user/id".to_string()))
+    );
+}
@@ -0,0 +1,138 @@
+use super::*;
+
+fn gen_parser(optional: bool) -> MessyJson {
+    let nested_string = MessyJson::from(MessyJsonInner::String(MessyJsonScalar::new(false)));
+    let nested_number = MessyJson::from(MessyJsonInner::Number(MessyJsonNumeric::new(
+        MessyJsonNumberType::U64,
+        false,
+    )));
+    let item = MessyJson::from(MessyJsonInner::Obj(MessyJsonObject::from(
+        MessyJsonObjectInner::new(
+            vec![
+                (gen_key("name"), nested_string),
+                (gen_key("id"), nested_number),
+            ]
+            .into_iter()
+            .collect(),
+            false,
+        ),
+    )));
+    MessyJson::from(MessyJsonInner::Array(MessyJsonArray::new(item, optional)))
+}
+
+const VAL: &str = r#"[
+	{ "name": "a", "id": 1 },
+	{ "name": "b", "id": 2 },
+	{ "name": "c", "id": 3 }
+]"#;
+
+#[test]
+fn items() {
+    let parser = gen_parser(false);
+    let mut deserializer = serde_json::Deserializer::from_reader(VAL.as_bytes());
+    let mut names: Vec<String> = Vec::new();
+    let count = parser
+        .builder(MessyJsonSettings::default())
+        .for_each_item(&mut deserializer, |item| {
+            names.push(item.inner()["name"].as_str().unwrap().to_string());
+            Ok::<(), MessyJsonError>(())
+        })
+        .unwrap();
+
+    assert_eq!(count, 3);
+    assert_eq!(names, vec!["a", "b", "c"]);
+}
+
+#[test]
+fn stop() {
+    let parser = gen_parser(false);
+    let mut deserializer = serde_json::Deserializer::from_str(VAL);
+    let mut seen = 0;
+    let err = parser
+        .builder(MessyJsonSettings::default())
+        .for_each_item(&mut deserializer, |item| {
+            seen += 1;
+            match item.inner()["id"].as_u128() {
+                Some(2) => Err("stopping at 2"),
+                _ => Ok(()),
+            }
+        })
+        .unwrap_err();
+
+    assert_eq!(seen, 2);
+    assert_eq!(err.to_string().contains("stopping at 2"), true);
+}
+
+#[test]
+fn invalid_item() {
+    let parser = gen_parser(false);
+    let mut deserializer = serde_json::Deserializer::from_str(r#"[{ "name": "a", "id": 1 }, 1]"#);
+    let mut seen = 0;
+
+    parser
+        .builder(MessyJsonSettings::default())
+        .for_each_item(&mut deserializer, |_| {
+            seen += 1;
+            Ok::<(), MessyJsonError>(())
+        })
+        .unwrap_err();
+    assert_eq!(seen, 1);
+}
+
+#[test]
+fn projected() {
+    let parser = gen_parser(false);
+    let mut deserializer = serde_json::Deserializer::from_str(VAL);
+
+    parser
+        .builder(MessyJsonSettings::default())
+        .with_projection(
+            MessyJsonProjection::from_pointers(["/id"]).unwrap(),
+            MessyJsonProjectionMode::Skip,
+        )
+        .for_each_item(&mut deserializer, |item| {
+            assert_eq!(item.inner().get("name").is_none(), true);
+            Ok::<(), MessyJsonError>(())
+        })
+        .unwrap();
+}
+
+#[test]
+fn optional_and_limits() {
+    let mut deserializer = serde_json::Deserializer::from_str("null");
+    assert_eq!(
+        gen_parser(true)
+            .builder(MessyJsonSettings::default())
+            .for_each_item(&mut deserializer, |_| Ok::<(), MessyJsonError>(()))
+            .unwrap(),
+        0
+    );
+    let mut deserializer = serde_json::Deserializer::from_str(VAL);
+    let mut count = 0;
+    gen_parser(false)
+        .builder(MessyJsonSettings {
+            limits: MessyJsonLimits {
+                max_array_length: Some(2),
+                ..MessyJsonLimits::default()
+            },
+            ..MessyJsonSettings::default()
+        })
+        .for_each_item(&mut deserializer, |_| {
+            count += 1;
+            Ok::<(), MessyJsonError>(())
+        })
+        .unwrap_err();
+    // The item exceeding the limit isn't handed to the callback
+    assert_eq!(count, 2);
+}
+
+#[test]
+fn not_an_array() {
+    let parser = MessyJson::from(MessyJsonInner::Bool(MessyJsonScalar::new(false)));
+    let mut deserializer = serde_json::Deserializer::from_str("[true]");
+
+    parser
+        .builder(MessyJsonSettings::default())
+        .for_each_item(&mut deserializer, |_| Ok::<(), MessyJsonError>(()))
+        .unwrap_err();
+}
@@ -0,0 +1,224 @@
+use super::*;
+
+fn gen_obj() -> MessyJsonObject {
+    let nested_string = MessyJson::from(MessyJsonInner::String(MessyJsonScalar::new(false)));
+    let nested_opt = MessyJson::from(MessyJsonInner::String(MessyJsonScalar::new(true)));
+    let nested_address = MessyJson::from(MessyJsonInner::Obj(MessyJsonObject::from(
+        MessyJsonObjectInner::new(
+            vec![
+                (gen_key("city"), nested_string.clone()),
+                (gen_key("street"), nested_opt.clone()),
+            ]
+            .into_iter()
+            .collect(),
+            false,
+        ),
+    )));
+    let nested_addresses = MessyJson::from(MessyJsonInner::Array(MessyJsonArray::new(
+        nested_address,
+        false,
+    )));
+    MessyJsonObject::from(MessyJsonObjectInner::new(
+        vec![
+            (gen_key("id"), nested_string.clone()),
+            (gen_key("name"), nested_string.clone()),
+            (gen_key("password"), nested_string),
+            (gen_key("nickname"), nested_opt),
+            (gen_key("addresses"), nested_addresses),
+        ]
+        .into_iter()
+        .collect(),
+        false,
+    ))
+}
+
+fn parse(schema: &MessyJson, value: &str) -> Result<(), serde_json::Error> {
+    let mut deserializer = serde_json::Deserializer::from_str(value);
+    schema
+        .builder(MessyJsonSettings::default())
+        .deserialize(&mut deserializer)
+        .map(|_| ())
+}
+
+#[test]
+fn pick_omit() {
+    let obj = gen_obj();
+    let picked = obj.pick(["id", "name", "unknown"]);
+    let omitted = obj.omit(["password", "addresses", "nickname"]);
+
+    assert_eq!(picked, omitted);
+    assert_eq!(
+        picked
+            .properties()
+            .keys()
+            .map(|x| x.as_str())
+            .collect::<Vec<_>>(),
+        vec!["id", "name"]
+    );
+    assert_eq!(obj.properties().len(), 5);
+    parse(
+        &MessyJson::from(MessyJsonInner::Obj(picked)),
+        r#"{ "id": "1", "name": "Jean" }"#,
+    )
+    .unwrap();
+}
+
+#[test]
+fn partial() {
+    let schema = MessyJson::from(MessyJsonInner::Obj(gen_obj()));
+    let shallow = schema.partial().unwrap();
+    let deep = schema.deep_partial().unwrap();
+
+    parse(&schema, r#"{ "addresses": [] }"#).unwrap_err();
+    parse(&shallow, r#"{ "addresses": [] }"#).unwrap();
+    parse(
+        &shallow,
+        r#"{ "addresses": [{ "street": "Rue de Rivoli" }] }"#,
+    )
+    .unwrap_err();
+    parse(&deep, r#"{ "addresses": [{ "street": "Rue de Rivoli" }] }"#).unwrap();
+    assert_eq!(deep.optional(), false);
+}
+
+#[test]
+fn required() {
+    let schema = MessyJson::from(MessyJsonInner::Obj(gen_obj()));
+    let required = schema.partial().unwrap().required().unwrap();
+
+    assert_eq!(
+        required,
+        schema.omit(Vec::<&str>::new()).unwrap().required().unwrap()
+    );
+    parse(
+        &required,
+        r#"{ "id": "1", "name": "Jean", "password": "1234", "addresses": [] }"#,
+    )
+    .unwrap_err();
+    parse(
+        &required,
+        r#"{ "id": "1", "name": "Jean", "password": "1234", "nickname": "JJ", "addresses": [] }"#,
+    )
+    .unwrap();
+}
+
+#[test]
+fn extend() {
+    let obj = gen_obj();
+    let extension = MessyJsonObject::from(MessyJsonObjectInner::new(
+        vec![
+            (
+                gen_key("password"),
+                MessyJson::from(MessyJsonInner::String(MessyJsonScalar::new(true))),
+            ),
+            (
+                gen_key("age"),
+                MessyJson::from(MessyJsonInner::Number(MessyJsonNumeric::new(
+                    MessyJsonNumberType::U64,
+                    false,
+                ))),
+            ),
+        ]
+        .into_iter()
+        .collect(),
+        false,
+    ));
+    let extended = obj.extend(&extension);
+
+    assert_eq!(extended.properties().len(), 6);
+    assert_eq!(extended.properties()["password"].optional(), true);
+    assert_eq!(obj.properties()["password"].optional(), false);
+}
+
+#[test]
+fn not_an_object() {
+    let schema = MessyJson::from(MessyJsonInner::String(MessyJsonScalar::new(false)));
+
+    assert_eq!(schema.partial(), Err(MessyJsonError::NotAnObject));
+    assert_eq!(schema.with_optional(true).optional(), true);
+    assert_eq!(schema.optional(), false);
+}
+
+fn gen_mixin(keys: &[(&str, MessyJson)]) -> MessyJsonObject {
+    MessyJsonObject::from(MessyJsonObjectInner::new(
+        keys.iter()
+            .map(|(key, val)| (gen_key(key), val.clone()))
+            .collect(),
+        false,
+    ))
+}
+
+#[test]
+fn all_of() {
+    let string = MessyJson::from(MessyJsonInner::String(MessyJsonScalar::new(false)));
+    let string_opt = MessyJson::from(MessyJsonInner::String(MessyJsonScalar::new(true)));
+    let owner = MessyJson::from(MessyJsonInner::Obj(gen_mixin(&[(
+        "id",
+        string_opt.clone(),
+    )])));
+    let owner_name = MessyJson::from(MessyJsonInner::Obj(gen_mixin(&[("name", string.clone())])));
+    let timestamps = gen_mixin(&[
+        ("created_at", string.clone()),
+        ("updated_at", string_opt.clone()),
+    ]);
+    let ownership = gen_mixin(&[("owner", owner), ("updated_at", string.clone())]);
+    let resource = gen_mixin(&[("owner", owner_name), ("title", string_opt)]);
+
+    let merged = MessyJsonObject::all_of(&[timestamps, ownership, resource]).unwrap();
+    assert_eq!(
+        merged
+            .properties()
+            .keys()
+            .map(|x| x.as_str())
+            .collect::<Vec<_>>(),
+        vec!["created_at", "owner", "title", "updated_at"]
+    );
+    assert_eq!(merged.properties()["updated_at"].optional(), false);
+    let schema = MessyJson::from(MessyJsonInner::Obj(merged));
+    parse(
+        &schema,
+        r#"{ "created_at": "now", "updated_at": "now", "owner": { "name": "Jean" } }"#,
+    )
+    .unwrap();
+    parse(
+        &schema,
+        r#"{ "created_at": "now", "updated_at": "now", "owner": { "id": "1" } }"#,
+    )
+    .unwrap_err();
+}
+
+#[test]
+fn merge_conflict() {
+    let string = MessyJson::from(MessyJsonInner::String(MessyJsonScalar::new(false)));
+    let number = MessyJson::from(MessyJsonInner::Number(MessyJsonNumeric::new(
+        MessyJsonNumberType::U128,
+        false,
+    )));
+    let small_number = MessyJson::from(MessyJsonInner::Number(MessyJsonNumeric::new(
+        MessyJsonNumberType::U64,
+        true,
+    )));
+    let nested = |val: &MessyJson| {
+        MessyJson::from(MessyJsonInner::Obj(gen_mixin(&[(
+            "nested",
+            MessyJson::from(MessyJsonInner::Array(MessyJsonArray::new(
+                val.clone(),
+                false,
+            ))),
+        )])))
+    };
+
+    assert_eq!(
+        nested(&string).merge(&nested(&number)),
+        Err(MessyJsonError::SchemaConflict("/nested/-".to_string()))
+    );
+    assert_eq!(
+        MessyJson::all_of(&[nested(&number), nested(&small_number)]),
+        Ok(nested(&small_number.with_optional(false)))
+    );
+    assert_eq!(
+        MessyJson::all_of(&[]),
+        Ok(MessyJson::from(MessyJsonInner::Obj(
+            MessyJsonObject::default()
+        )))
+    );
+}
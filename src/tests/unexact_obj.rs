@@ -333,3 +333,56 @@ fn nested_optional_parent_optional() {
         .deserialize(&mut deserializer)
         .unwrap();
 }
+
+#[test]
+fn ignore_unknown_keys() {
+    let nested_string = MessyJson::from(MessyJsonInner::String(MessyJsonScalar::new(false)));
+    let nested_obj = MessyJson::from(MessyJsonInner::Obj(MessyJsonObject::from(
+        MessyJsonObjectInner::new(
+            vec![(gen_key("hello"), nested_string.clone())]
+                .into_iter()
+                .collect(),
+            false,
+        ),
+    )));
+    let schema = MessyJson::from(MessyJsonInner::Obj(MessyJsonObject::from(
+        MessyJsonObjectInner::new(
+            vec![
+                (gen_key("hello"), nested_string),
+                (gen_key("nested"), nested_obj),
+            ]
+            .into_iter()
+            .collect(),
+            false,
+        ),
+    )));
+    let value = r#"
+	{
+		"hello": "world",
+		"whoami": { "deeply": [1, "nested", null, { "object": true }] },
+		"nested": {
+			"hello": "monde",
+			"whoami": "wellidk"
+		},
+		"whoami": "again"
+	}
+	"#;
+
+    let mut deserializer = serde_json::Deserializer::from_str(value);
+    let parsed = schema
+        .builder(MessyJsonSettings {
+            ignore_unknown_keys: true,
+            ..MessyJsonSettings::default()
+        })
+        .deserialize(&mut deserializer)
+        .unwrap();
+    assert_eq!(
+        parsed.inner(),
+        &serde_json::json!({
+            "hello": "world",
+            "nested": {
+                "hello": "monde"
+            }
+        })
+    );
+}
@@ -1,4 +1,5 @@
 use super::*;
+use std::convert::TryFrom;
 
 #[test]
 fn uuid_simple() {
@@ -169,3 +170,71 @@ fn optional_uuid_absent() {
         _ => panic!("..."),
     };
 }
+
+#[test]
+fn uuid_to_json() {
+    let test_uuid = feat_uuid::Uuid::parse_str("31ee8240-630b-416a-8c54-0e2a0d070488").unwrap();
+    let val = MessyJsonValue::Uuid(Cow::Owned(test_uuid));
+
+    assert_eq!(
+        serde_json::Value::try_from(val).unwrap(),
+        serde_json::json!("31ee8240-630b-416a-8c54-0e2a0d070488")
+    );
+}
+
+#[test]
+fn uuid_cmp_json() {
+    let test_uuid = feat_uuid::Uuid::parse_str("31ee8240-630b-416a-8c54-0e2a0d070488").unwrap();
+    let val = MessyJsonValue::Uuid(Cow::Owned(test_uuid));
+
+    assert_eq!(
+        val.eq(&serde_json::json!("31ee8240-630b-416a-8c54-0e2a0d070488")),
+        true
+    );
+    assert_eq!(
+        val.eq(&serde_json::json!("31ee8240-630b-416a-8c54-0e2a0d070489")),
+        false
+    );
+}
+
+#[test]
+fn uuid_coercion() {
+    let nested_uuid = MessyJson::from(MessyJsonInner::Uuid(MessyJsonScalar::new(false)));
+    let test_uuid = feat_uuid::Uuid::parse_str("31ee8240-630b-416a-8c54-0e2a0d070488").unwrap();
+    let schema = MessyJson::from(MessyJsonInner::Obj(MessyJsonObject::from(
+        MessyJsonObjectInner::new(
+            vec![(gen_key("hello"), nested_uuid)].into_iter().collect(),
+            false,
+        ),
+    )));
+
+    // UUIDs are read from any of their string representations, whatever the coercion
+    for coerce_types in [false, true].iter() {
+        let settings = MessyJsonSettings {
+            coerce_types: *coerce_types,
+            ..MessyJsonSettings::default()
+        };
+        for value in [
+            "31ee8240-630b-416a-8c54-0e2a0d070488",
+            "31EE8240630B416A8C540E2A0D070488",
+            "31EE8240-630B-416A-8C54-0E2A0D070488",
+        ]
+        .iter()
+        {
+            let value = format!(r#"{{ "hello": "{}" }}"#, value);
+            let mut deserializer = serde_json::Deserializer::from_str(&value);
+            let parsed = schema
+                .builder(settings)
+                .deserialize(&mut deserializer)
+                .unwrap();
+            assert_eq!(parsed.inner()["hello"].as_uuid(), Some(&test_uuid));
+        }
+        let mut deserializer = serde_json::Deserializer::from_str(
+            r#"{ "hello": 66259594233411469567384402453700732040 }"#,
+        );
+        schema
+            .builder(settings)
+            .deserialize(&mut deserializer)
+            .unwrap_err();
+    }
+}
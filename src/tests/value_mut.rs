@@ -0,0 +1,177 @@
+use super::*;
+
+fn gen_parser() -> MessyJson {
+    let nested_string = MessyJson::from(MessyJsonInner::String(MessyJsonScalar::new(false)));
+    let nested_opt = MessyJson::from(MessyJsonInner::String(MessyJsonScalar::new(true)));
+    let nested_number = MessyJson::from(MessyJsonInner::Number(MessyJsonNumeric::new(
+        MessyJsonNumberType::U64,
+        false,
+    )));
+    let nested_array = MessyJson::from(MessyJsonInner::Array(MessyJsonArray::new(
+        nested_number,
+        false,
+    )));
+    let nested_obj = MessyJson::from(MessyJsonInner::Obj(MessyJsonObject::from(
+        MessyJsonObjectInner::new(
+            vec![
+                (gen_key("created_at"), nested_opt.clone()),
+                (gen_key("numbers"), nested_array),
+            ]
+            .into_iter()
+            .collect(),
+            false,
+        ),
+    )));
+    MessyJson::from(MessyJsonInner::Obj(MessyJsonObject::from(
+        MessyJsonObjectInner::new(
+            vec![
+                (gen_key("id"), nested_opt),
+                (gen_key("name"), nested_string),
+                (gen_key("meta"), nested_obj),
+            ]
+            .into_iter()
+            .collect(),
+            false,
+        ),
+    )))
+}
+
+fn gen_value(parser: &MessyJson) -> MessyJsonValue<'static> {
+    const VAL: &str = r#"
+	{
+		"name": "hello",
+		"meta": {
+			"numbers": [1, 2]
+		}
+	}
+	"#;
+    let mut deserializer = serde_json::Deserializer::from_str(VAL);
+    parser
+        .builder(MessyJsonSettings::default())
+        .deserialize(&mut deserializer)
+        .unwrap()
+        .take()
+}
+
+#[test]
+fn insert() {
+    let parser = gen_parser();
+    let mut value = gen_value(&parser);
+    let mut value_mut = parser.edit(&mut value, MessyJsonSettings::default());
+
+    let old = value_mut
+        .insert("id", MessyJsonValue::String(Cow::Borrowed("1234")))
+        .unwrap();
+    assert_eq!(old.map(|x| x.is_absent()), Some(true));
+    value_mut
+        .insert("id", MessyJsonValue::Number(1234))
+        .unwrap_err();
+    value_mut
+        .insert("unknown", MessyJsonValue::Number(1234))
+        .unwrap_err();
+    assert_eq!(value["id"].as_str(), Some("1234"));
+}
+
+#[test]
+fn remove() {
+    let parser = gen_parser();
+    let mut value = gen_value(&parser);
+    let mut value_mut = parser.edit(&mut value, MessyJsonSettings::default());
+
+    value_mut.remove("name").unwrap_err();
+    value_mut.remove("id").unwrap();
+    assert_eq!(value["name"].as_str(), Some("hello"));
+    assert_eq!(value["id"].is_absent(), true);
+}
+
+#[test]
+fn pointer_mut() {
+    let parser = gen_parser();
+    let mut value = gen_value(&parser);
+    let mut value_mut = parser.edit(&mut value, MessyJsonSettings::default());
+
+    value_mut
+        .pointer_mut("/meta")
+        .unwrap()
+        .insert(
+            "created_at",
+            MessyJsonValue::String(Cow::Borrowed("2021-05-20")),
+        )
+        .unwrap();
+    value_mut
+        .pointer_mut("/meta/numbers/1")
+        .unwrap()
+        .set(MessyJsonValue::Number(3))
+        .unwrap();
+    value_mut
+        .pointer_mut("/meta/numbers/0")
+        .unwrap()
+        .set(MessyJsonValue::Bool(true))
+        .unwrap_err();
+    value_mut
+        .pointer_mut("/meta/numbers")
+        .unwrap()
+        .set(MessyJsonValue::Array(MessyJsonArrayValue::from(vec![
+            MessyJsonValue::String(Cow::Borrowed("hello")),
+        ])))
+        .unwrap_err();
+    assert_eq!(value_mut.pointer_mut("/meta/numbers/2").is_none(), true);
+    assert_eq!(
+        value,
+        serde_json::json!({
+            "name": "hello",
+            "meta": {
+                "created_at": "2021-05-20",
+                "numbers": [1, 3]
+            }
+        })
+    );
+}
+
+#[test]
+fn preserve_mandatory() {
+    let parser = gen_parser();
+    let mut value = gen_value(&parser);
+    let settings = MessyJsonSettings {
+        all_optional: true,
+        preserve_mandatory: true,
+        ..MessyJsonSettings::default()
+    };
+    let mut value_mut = parser.edit(&mut value, settings);
+    let null = MessyJsonValue::Null(
+        MessyJsonNullType::Null,
+        MessyJsonExpected::Root(parser.clone()),
+    );
+
+    value_mut.insert("name", null.clone()).unwrap_err();
+    value_mut.insert("id", null).unwrap();
+    value_mut.remove("name").unwrap();
+}
+
+#[test]
+fn unchecked() {
+    let parser = gen_parser();
+    let mut value = gen_value(&parser);
+
+    *value.pointer_mut("/meta/numbers/0").unwrap() = MessyJsonValue::Bool(true);
+    value
+        .as_object_mut()
+        .unwrap()
+        .insert(gen_key("unknown"), MessyJsonValue::Bool(true));
+    parser
+        .validate(&value, MessyJsonSettings::default())
+        .unwrap_err();
+    value.as_object_mut().unwrap().remove("unknown");
+    parser
+        .validate(&value, MessyJsonSettings::default())
+        .unwrap_err();
+    value
+        .get_mut("meta")
+        .and_then(MessyJsonValue::as_object_mut)
+        .unwrap()
+        .remove("created_at");
+    *value.pointer_mut("/meta/numbers/0").unwrap() = MessyJsonValue::Number(1);
+    parser
+        .validate(&value, MessyJsonSettings::default())
+        .unwrap();
+}
@@ -0,0 +1,300 @@
+use super::*;
+
+/// Make every property of an object optional, recursing into nested objects if `deep` is set
+fn partial_obj(obj: &mut MessyJsonObjectInner, deep: bool) {
+    for property in obj.properties_mut().values_mut() {
+        let inner = property.inner_mut();
+        inner.set_optional(true);
+        if deep {
+            partial_nested(inner);
+        }
+    }
+    obj.prune();
+}
+
+/// Make the properties of the objects nested in a schema optional
+fn partial_nested(schema: &mut MessyJsonInner) {
+    match schema {
+        MessyJsonInner::Obj(obj) => partial_obj(obj.inner_mut(), true),
+        MessyJsonInner::Array(arr) => partial_nested(arr.items_mut().inner_mut()),
+        _ => (),
+    }
+}
+
+/// Merge two schemas, a value having to match both
+fn merge_schema(
+    left: &MessyJson,
+    right: &MessyJson,
+    path: &mut String,
+) -> Result<MessyJson, MessyJsonError> {
+    if left == right {
+        return Ok(left.clone());
+    }
+    let mut res = match (left.deref(), right.deref()) {
+        (MessyJsonInner::Obj(l_obj), MessyJsonInner::Obj(r_obj)) => {
+            MessyJsonInner::Obj(merge_obj(l_obj, r_obj, path)?)
+        }
+        (MessyJsonInner::Array(l_arr), MessyJsonInner::Array(r_arr)) => {
+            let mut arr = l_arr.clone();
+            let len = path.len();
+            pointer::push_token(path, "-");
+            *arr.items_mut() = merge_schema(l_arr.items(), r_arr.items(), path)?;
+            path.truncate(len);
+            MessyJsonInner::Array(arr)
+        }
+        (MessyJsonInner::Number(l_num), MessyJsonInner::Number(r_num)) => match l_num.type_() {
+            MessyJsonNumberType::U64 => MessyJsonInner::Number(*l_num),
+            MessyJsonNumberType::U128 => MessyJsonInner::Number(*r_num),
+        },
+        (MessyJsonInner::Bool(_), MessyJsonInner::Bool(_))
+        | (MessyJsonInner::String(_), MessyJsonInner::String(_)) => left.deref().clone(),
+        #[cfg(feature = "uuid")]
+        (MessyJsonInner::Uuid(_), MessyJsonInner::Uuid(_)) => left.deref().clone(),
+        _ => return Err(MessyJsonError::SchemaConflict(path.clone())),
+    };
+    res.set_optional(left.optional() && right.optional());
+    Ok(MessyJson::from(res))
+}
+
+/// Merge the properties of two objects
+fn merge_obj(
+    left: &MessyJsonObject,
+    right: &MessyJsonObject,
+    path: &mut String,
+) -> Result<MessyJsonObject, MessyJsonError> {
+    let mut res = left.clone();
+    let inner = res.inner_mut();
+    for (key, r_val) in right.properties() {
+        let val = match left.properties().get(key) {
+            Some(l_val) => {
+                let len = path.len();
+                pointer::push_token(path, key);
+                let val = merge_schema(l_val, r_val, path)?;
+                path.truncate(len);
+                val
+            }
+            None => r_val.clone(),
+        };
+        inner.properties_mut().insert(key.clone(), val);
+        let access = match (left.access(key), right.access(key)) {
+            (l_access, r_access) if l_access == r_access => l_access,
+            (MessyJsonFieldAccess::ReadWrite, access)
+            | (access, MessyJsonFieldAccess::ReadWrite) => access,
+            _ => {
+                pointer::push_token(path, key);
+                return Err(MessyJsonError::SchemaConflict(path.clone()));
+            }
+        };
+        inner.access_mut().insert(key.clone(), access);
+    }
+    for (alias, property) in right.aliases() {
+        match left.aliases().get(alias) {
+            Some(l_property) if l_property != property => {
+                pointer::push_token(path, alias);
+                return Err(MessyJsonError::SchemaConflict(path.clone()));
+            }
+            _ => {
+                inner.aliases_mut().insert(alias.clone(), property.clone());
+            }
+        }
+    }
+    inner.prune();
+    inner.set_optional(left.optional() && right.optional());
+    Ok(res)
+}
+
+impl MessyJsonObject {
+    /// Create a new object only keeping the listed properties
+    ///
+    /// Keys unknown to this object are ignored.
+    pub fn pick<I, S>(&self, keys: I) -> MessyJsonObject
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        let properties = keys
+            .into_iter()
+            .filter_map(|key| {
+                self.properties()
+                    .get_key_value(key.as_ref())
+                    .map(|(key, val)| (key.clone(), val.clone()))
+            })
+            .collect();
+        let mut res = self.clone();
+        let inner = res.inner_mut();
+        *inner.properties_mut() = properties;
+        inner.prune();
+        res
+    }
+
+    /// Create a new object without the listed properties
+    ///
+    /// Keys unknown to this object are ignored.
+    pub fn omit<I, S>(&self, keys: I) -> MessyJsonObject
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        let mut res = self.clone();
+        let inner = res.inner_mut();
+        for key in keys {
+            inner.properties_mut().remove(key.as_ref());
+        }
+        inner.prune();
+        res
+    }
+
+    /// Create a new object where every property is optional
+    pub fn partial(&self) -> MessyJsonObject {
+        let mut res = self.clone();
+        partial_obj(res.inner_mut(), false);
+        res
+    }
+
+    /// Create a new object where every property is optional, as well as the properties
+    /// of every nested object, including the ones inside arrays
+    pub fn deep_partial(&self) -> MessyJsonObject {
+        let mut res = self.clone();
+        partial_obj(res.inner_mut(), true);
+        res
+    }
+
+    /// Create a new object where every property is mandatory
+    pub fn required(&self) -> MessyJsonObject {
+        let mut res = self.clone();
+        let inner = res.inner_mut();
+        for property in inner.properties_mut().values_mut() {
+            property.inner_mut().set_optional(false);
+        }
+        inner.prune();
+        res
+    }
+
+    /// Create a new object with the properties of both objects.
+    ///
+    /// The properties of `other` replace the ones of this object.
+    pub fn extend(&self, other: &MessyJsonObject) -> MessyJsonObject {
+        let mut res = self.clone();
+        let inner = res.inner_mut();
+        for (key, val) in other.properties() {
+            inner.properties_mut().insert(key.clone(), val.clone());
+            inner.access_mut().insert(key.clone(), other.access(key));
+        }
+        inner.aliases_mut().extend(
+            other
+                .aliases()
+                .iter()
+                .map(|(alias, property)| (alias.clone(), property.clone())),
+        );
+        inner.prune();
+        res
+    }
+
+    /// Create a new object with the properties of both objects, a value having to match both.
+    ///
+    /// - A key present in both objects must have compatible types, its schemas being merged recursively.
+    /// - A key is optional if it's optional in both objects.
+    /// - Merging a `U64` number with a `U128` number gives a `U64` number.
+    pub fn merge(&self, other: &MessyJsonObject) -> Result<MessyJsonObject, MessyJsonError> {
+        merge_obj(self, other, &mut String::new())
+    }
+
+    /// Merge a list of objects, like the `allOf` keyword of JSON Schema.
+    ///
+    /// See [merge](MessyJsonObject::merge) for the merging rules.
+    pub fn all_of<'a, I>(objects: I) -> Result<MessyJsonObject, MessyJsonError>
+    where
+        I: IntoIterator<Item = &'a MessyJsonObject>,
+    {
+        let mut objects = objects.into_iter();
+        let first = objects.next().cloned().unwrap_or_default();
+        objects.try_fold(first, |acc, obj| acc.merge(obj))
+    }
+
+    /// Create a new object with a different optional flag
+    pub fn with_optional(&self, optional: bool) -> MessyJsonObject {
+        let mut res = self.clone();
+        res.inner_mut().set_optional(optional);
+        res
+    }
+}
+
+impl MessyJson {
+    /// Apply an object transformation, failing if this schema isn't an object
+    fn map_obj<F>(&self, f: F) -> Result<MessyJson, MessyJsonError>
+    where
+        F: FnOnce(&MessyJsonObject) -> MessyJsonObject,
+    {
+        match self.deref() {
+            MessyJsonInner::Obj(obj) => Ok(MessyJson::from(MessyJsonInner::Obj(f(obj)))),
+            _ => Err(MessyJsonError::NotAnObject),
+        }
+    }
+
+    /// See [MessyJsonObject::pick](MessyJsonObject::pick)
+    pub fn pick<I, S>(&self, keys: I) -> Result<MessyJson, MessyJsonError>
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        self.map_obj(|obj| obj.pick(keys))
+    }
+
+    /// See [MessyJsonObject::omit](MessyJsonObject::omit)
+    pub fn omit<I, S>(&self, keys: I) -> Result<MessyJson, MessyJsonError>
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        self.map_obj(|obj| obj.omit(keys))
+    }
+
+    /// See [MessyJsonObject::partial](MessyJsonObject::partial)
+    pub fn partial(&self) -> Result<MessyJson, MessyJsonError> {
+        self.map_obj(MessyJsonObject::partial)
+    }
+
+    /// See [MessyJsonObject::deep_partial](MessyJsonObject::deep_partial)
+    pub fn deep_partial(&self) -> Result<MessyJson, MessyJsonError> {
+        self.map_obj(MessyJsonObject::deep_partial)
+    }
+
+    /// See [MessyJsonObject::required](MessyJsonObject::required)
+    pub fn required(&self) -> Result<MessyJson, MessyJsonError> {
+        self.map_obj(MessyJsonObject::required)
+    }
+
+    /// See [MessyJsonObject::extend](MessyJsonObject::extend)
+    pub fn extend(&self, other: &MessyJsonObject) -> Result<MessyJson, MessyJsonError> {
+        self.map_obj(|obj| obj.extend(other))
+    }
+
+    /// Merge two schemas, a value having to match both.
+    ///
+    /// See [MessyJsonObject::merge](MessyJsonObject::merge) for the merging rules.
+    pub fn merge(&self, other: &MessyJson) -> Result<MessyJson, MessyJsonError> {
+        merge_schema(self, other, &mut String::new())
+    }
+
+    /// Merge a list of schemas, like the `allOf` keyword of JSON Schema.
+    ///
+    /// An empty list gives an empty object.
+    pub fn all_of<'a, I>(schemas: I) -> Result<MessyJson, MessyJsonError>
+    where
+        I: IntoIterator<Item = &'a MessyJson>,
+    {
+        let mut schemas = schemas.into_iter();
+        let first = schemas
+            .next()
+            .cloned()
+            .unwrap_or_else(|| MessyJson::from(MessyJsonInner::Obj(MessyJsonObject::default())));
+        schemas.try_fold(first, |acc, schema| acc.merge(schema))
+    }
+
+    /// Create a new schema with a different optional flag
+    pub fn with_optional(&self, optional: bool) -> MessyJson {
+        let mut res = self.clone();
+        res.inner_mut().set_optional(optional);
+        res
+    }
+}
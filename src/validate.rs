@@ -0,0 +1,103 @@
+use super::*;
+use serde::de::value::Error;
+use serde::de::Error as _;
+
+/// Describe a value for error reporting
+fn unexpected<'a>(value: &'a MessyJsonValue) -> serde::de::Unexpected<'a> {
+    match value {
+        MessyJsonValue::Array(_) => serde::de::Unexpected::Seq,
+        MessyJsonValue::Bool(x) => serde::de::Unexpected::Bool(*x),
+        MessyJsonValue::Number(_) => serde::de::Unexpected::Other("number"),
+        MessyJsonValue::Obj(_) => serde::de::Unexpected::Map,
+        MessyJsonValue::String(x) => serde::de::Unexpected::Str(x.as_ref()),
+        #[cfg(feature = "uuid")]
+        MessyJsonValue::Uuid(_) => serde::de::Unexpected::Other("uuid"),
+        MessyJsonValue::Null(_, _) => serde::de::Unexpected::Unit,
+    }
+}
+
+/// Check that an object value matches its schema
+fn validate_obj(
+    schema: &MessyJsonObject,
+    settings: &MessyJsonSettings,
+    value: &MessyJsonObjectValue,
+) -> Result<(), Error> {
+    for (key, val_schema) in schema.properties().iter() {
+        if !schema.allows(key, settings) {
+            continue;
+        }
+        match (value.get(key), val_schema.optional()) {
+            (Some(MessyJsonValue::Null(MessyJsonNullType::Null, _)), false)
+                if settings.all_optional() && settings.preserve_mandatory() =>
+            {
+                return Err(Error::custom(format!(
+                    "Can't force mandatory key to null `{}`",
+                    key
+                )))
+            }
+            (None, false) | (Some(MessyJsonValue::Null(MessyJsonNullType::Absent, _)), false)
+                if !settings.all_optional() =>
+            {
+                return Err(Error::custom(format!("Missing key `{}`", key)))
+            }
+            _ => (),
+        }
+    }
+    for (key, val) in value.iter() {
+        let val_schema = schema
+            .properties()
+            .get(key)
+            .ok_or_else(|| crate::schema_visitor::messy_json_unknown_key::<Error>(schema, key))?;
+        if !schema.allows(key, settings) {
+            match val.is_absent() {
+                true => continue,
+                false => return Err(crate::schema_visitor::messy_json_forbidden_key(schema, key)),
+            }
+        }
+        validate_value(val_schema, settings, val)?;
+    }
+    Ok(())
+}
+
+/// Check that a value matches its schema
+pub(crate) fn validate_value(
+    schema: &MessyJson,
+    settings: &MessyJsonSettings,
+    value: &MessyJsonValue,
+) -> Result<(), Error> {
+    match (schema.deref(), value) {
+        (_, MessyJsonValue::Null(_, _)) if schema.optional() || settings.all_optional() => Ok(()),
+        (MessyJsonInner::Bool(_), MessyJsonValue::Bool(_)) => Ok(()),
+        (MessyJsonInner::String(_), MessyJsonValue::String(_)) => Ok(()),
+        (MessyJsonInner::Number(opt), MessyJsonValue::Number(x)) => match opt.type_() {
+            MessyJsonNumberType::U64 if *x > u64::MAX as u128 => Err(Error::invalid_value(
+                serde::de::Unexpected::Other("128 bits number"),
+                &"a 64 bits number",
+            )),
+            _ => Ok(()),
+        },
+        #[cfg(feature = "uuid")]
+        (MessyJsonInner::Uuid(_), MessyJsonValue::Uuid(_)) => Ok(()),
+        (MessyJsonInner::Array(arr), MessyJsonValue::Array(x)) => x
+            .iter()
+            .try_for_each(|elem| validate_value(arr.items(), settings, elem)),
+        (MessyJsonInner::Obj(obj), MessyJsonValue::Obj(x)) => validate_obj(obj, settings, x),
+        (schema, value) => Err(Error::invalid_type(
+            unexpected(value),
+            &crate::raw_value::expected_type(schema),
+        )),
+    }
+}
+
+impl MessyJson {
+    /// Check that an already built [MessyJsonValue](MessyJsonValue) matches this schema.
+    ///
+    /// Optional keys missing from an object are accepted, just like [absent](MessyJsonNullType::Absent) ones.
+    pub fn validate(
+        &self,
+        value: &MessyJsonValue,
+        settings: MessyJsonSettings,
+    ) -> Result<(), Error> {
+        validate_value(self, &settings, value)
+    }
+}
@@ -1,11 +1,10 @@
 use super::*;
-use serde_json::Value;
 use std::convert::From;
-use std::ops::Deref;
+use std::ops::{Deref, DerefMut};
 
 /// ## Deserialized JSON Object Value
 #[derive(Clone, Debug, Eq, PartialEq, Default)]
-pub struct MessyJsonObjectValue<'a>(BTreeMap<ArcStr, MessyJsonValue<'a>>);
+pub struct MessyJsonObjectValue<'a>(MessyJsonMap<ArcStr, MessyJsonValue<'a>>);
 
 /// ## Deserialized JSON Null Value
 #[derive(Clone, Debug, Eq, PartialEq)]
@@ -17,23 +16,52 @@ pub enum MessyJsonNullType {
 }
 
 impl<'a> Deref for MessyJsonObjectValue<'a> {
-    type Target = BTreeMap<ArcStr, MessyJsonValue<'a>>;
+    type Target = MessyJsonMap<ArcStr, MessyJsonValue<'a>>;
 
     fn deref(&self) -> &Self::Target {
         &self.0
     }
 }
 
+impl<'a> DerefMut for MessyJsonObjectValue<'a> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
 impl<'a> MessyJsonObjectValue<'a> {
     /// Take the inner value, consuming the object
-    pub fn take(self) -> BTreeMap<ArcStr, MessyJsonValue<'a>> {
+    pub fn take(self) -> MessyJsonMap<ArcStr, MessyJsonValue<'a>> {
         self.0
     }
+
+    /// Insert a value without checking it against any schema.
+    ///
+    /// See [MessyJsonValueMut](MessyJsonValueMut) for schema-checked mutations.
+    #[inline]
+    pub fn insert(&mut self, key: ArcStr, value: MessyJsonValue<'a>) -> Option<MessyJsonValue<'a>> {
+        self.0.insert(key, value)
+    }
+
+    /// Remove a value without checking it against any schema.
+    ///
+    /// See [MessyJsonValueMut](MessyJsonValueMut) for schema-checked mutations.
+    #[inline]
+    pub fn remove(&mut self, key: &str) -> Option<MessyJsonValue<'a>> {
+        crate::map::remove(&mut self.0, key)
+    }
 }
 
+impl<'a> From<MessyJsonMap<ArcStr, MessyJsonValue<'a>>> for MessyJsonObjectValue<'a> {
+    fn from(obj: MessyJsonMap<ArcStr, MessyJsonValue<'a>>) -> Self {
+        MessyJsonObjectValue(obj)
+    }
+}
+
+#[cfg(feature = "preserve_order")]
 impl<'a> From<BTreeMap<ArcStr, MessyJsonValue<'a>>> for MessyJsonObjectValue<'a> {
     fn from(obj: BTreeMap<ArcStr, MessyJsonValue<'a>>) -> Self {
-        MessyJsonObjectValue(obj)
+        MessyJsonObjectValue(obj.into_iter().collect())
     }
 }
 
@@ -61,6 +89,12 @@ impl<'a> Deref for MessyJsonArrayValue<'a> {
     }
 }
 
+impl<'a> DerefMut for MessyJsonArrayValue<'a> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
 /// ## Deserialized JSON Value
 ///
 /// This structure holds references to the deserialized data.
@@ -68,6 +102,19 @@ impl<'a> Deref for MessyJsonArrayValue<'a> {
 /// It is structured like [Serde JSON's `Value`](serde_json::Value).
 ///
 /// Every string is borrowed rather than cloned
+///
+/// ### Conversions
+///
+/// This value can be converted to a [MessyJsonValueRaw](MessyJsonValueRaw) or a
+/// [serde_json::Value](serde_json::Value). The following rules apply :
+///
+/// - [Absent](MessyJsonNullType::Absent) keys are removed from objects, and become `null` anywhere else.
+/// - `uuid` values become their hyphenated string representation.
+/// - Numbers bigger than [u64::MAX](u64::MAX) can't be stored in a [serde_json::Number](serde_json::Number),
+///   failing the conversion with [MessyJsonError::NumberOverflow](MessyJsonError::NumberOverflow).
+///
+/// Converting back requires a schema, see [MessyJson::validate_raw](MessyJson::validate_raw)
+/// and [MessyJson::validate_value](MessyJson::validate_value).
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub enum MessyJsonValue<'a> {
     Array(MessyJsonArrayValue<'a>),
@@ -80,35 +127,6 @@ pub enum MessyJsonValue<'a> {
     Null(MessyJsonNullType, MessyJsonExpected),
 }
 
-impl<'a> PartialEq<Value> for MessyJsonObjectValue<'a> {
-    fn eq(&self, other: &Value) -> bool {
-        match other {
-            Value::Object(v_obj) => {
-                for (k, v) in self.iter() {
-                    if let Some(x) = v_obj.get(k.as_str()) {
-                        if v != x {
-                            return false;
-                        }
-                        continue;
-                    }
-                    return false;
-                }
-                true
-            }
-            _ => false,
-        }
-    }
-}
-
-impl<'a> PartialEq<Value> for MessyJsonArrayValue<'a> {
-    fn eq(&self, other: &Value) -> bool {
-        match other {
-            Value::Array(v_arr) => self.0.eq(v_arr),
-            _ => false,
-        }
-    }
-}
-
 #[cfg(feature = "uuid")]
 impl<'a> PartialEq<feat_uuid::Uuid> for MessyJsonValue<'a> {
     fn eq(&self, other: &feat_uuid::Uuid) -> bool {
@@ -119,26 +137,6 @@ impl<'a> PartialEq<feat_uuid::Uuid> for MessyJsonValue<'a> {
     }
 }
 
-impl<'a> PartialEq<Value> for MessyJsonValue<'a> {
-    fn eq(&self, other: &Value) -> bool {
-        match (self, other) {
-            (MessyJsonValue::Array(mj_arr), Value::Array(_)) => mj_arr.eq(other),
-            (MessyJsonValue::Bool(mj_bool), Value::Bool(v_bool)) => mj_bool == v_bool,
-            (MessyJsonValue::Number(mj_number), Value::Number(v_number)) => {
-                let num = match v_number.as_u64() {
-                    Some(x) => x,
-                    None => return false, // TODO Handle better
-                };
-                mj_number == &(num as u128)
-            }
-            (MessyJsonValue::Obj(mj_obj), Value::Object(_)) => mj_obj.eq(other),
-            (MessyJsonValue::String(mj_str), Value::String(v_str)) => mj_str == v_str,
-            (MessyJsonValue::Null(_, _), Value::Null) => true,
-            _ => false,
-        }
-    }
-}
-
 /// ## Container for [MessyJsonValue](MessyJsonValue)
 ///
 /// This structure is a simple wrapper around [MessyJsonValue](MessyJsonValue).
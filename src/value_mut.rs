@@ -0,0 +1,185 @@
+use super::*;
+use crate::validate::validate_value;
+
+/// Check a value about to be assigned, refusing to force a mandatory value to `null`
+/// if [preserve_mandatory](MessyJsonSettings::preserve_mandatory) is set.
+fn check_value(
+    schema: &MessyJson,
+    settings: &MessyJsonSettings,
+    value: &MessyJsonValue,
+) -> Result<(), MessyJsonError> {
+    validate_value(schema, settings, value)?;
+    if settings.all_optional()
+        && settings.preserve_mandatory()
+        && !schema.optional()
+        && value.is_null()
+    {
+        return Err(MessyJsonError::Validation(serde::de::Error::custom(
+            "Can't force mandatory value to null",
+        )));
+    }
+    Ok(())
+}
+
+/// ## Schema-checked mutable reference to a [MessyJsonValue](MessyJsonValue)
+///
+/// This structure binds a mutable [MessyJsonValue](MessyJsonValue) to its schema.
+/// Every modification made through it is checked against the schema, so that the value
+/// can't become invalid.
+///
+/// ```rust
+/// # use messy_json::*;
+/// # use std::borrow::Cow;
+/// # use serde::de::DeserializeSeed;
+/// let schema = MessyJson::from(MessyJsonInner::Obj(MessyJsonObject::from(MessyJsonObjectInner::new(
+///     vec![(
+///         arcstr::literal!("id"),
+///         MessyJson::from(MessyJsonInner::String(MessyJsonScalar::new(true))),
+///     )]
+///     .into_iter()
+///     .collect(),
+///     false,
+/// ))));
+/// let mut deserializer = serde_json::Deserializer::from_str("{}");
+/// let mut value = schema
+///     .builder(MessyJsonSettings::default())
+///     .deserialize(&mut deserializer)
+///     .unwrap()
+///     .take();
+/// let mut value_mut = schema.edit(&mut value, MessyJsonSettings::default());
+///
+/// value_mut.insert("id", MessyJsonValue::String(Cow::Borrowed("hello"))).unwrap();
+/// value_mut.insert("id", MessyJsonValue::Bool(true)).unwrap_err();
+/// value_mut.insert("unknown", MessyJsonValue::Bool(true)).unwrap_err();
+/// ```
+#[derive(Debug)]
+pub struct MessyJsonValueMut<'v, 'a> {
+    schema: MessyJson,
+    settings: MessyJsonSettings,
+    value: &'v mut MessyJsonValue<'a>,
+}
+
+impl<'v, 'a> MessyJsonValueMut<'v, 'a> {
+    /// Create a new [MessyJsonValueMut](MessyJsonValueMut).
+    ///
+    /// The value is expected to already match the schema.
+    pub fn new(
+        schema: &MessyJson,
+        value: &'v mut MessyJsonValue<'a>,
+        settings: MessyJsonSettings,
+    ) -> Self {
+        MessyJsonValueMut {
+            schema: schema.clone(),
+            settings,
+            value,
+        }
+    }
+
+    /// Get the schema of the value
+    #[inline]
+    pub fn schema(&self) -> &MessyJson {
+        &self.schema
+    }
+
+    /// Get the settings used to check the modifications
+    #[inline]
+    pub fn settings(&self) -> &MessyJsonSettings {
+        &self.settings
+    }
+
+    /// Get the underlying value
+    #[inline]
+    pub fn value(&self) -> &MessyJsonValue<'a> {
+        self.value
+    }
+
+    /// Replace the value, returning the previous one
+    pub fn set(&mut self, value: MessyJsonValue<'a>) -> Result<MessyJsonValue<'a>, MessyJsonError> {
+        check_value(&self.schema, &self.settings, &value)?;
+        Ok(std::mem::replace(self.value, value))
+    }
+
+    /// Get the object and its schema, if this value is an object
+    fn object(
+        &mut self,
+    ) -> Result<(&MessyJsonObject, &mut MessyJsonObjectValue<'a>), MessyJsonError> {
+        match (self.schema.deref(), &mut *self.value) {
+            (MessyJsonInner::Obj(schema), MessyJsonValue::Obj(value)) => Ok((schema, value)),
+            _ => Err(MessyJsonError::NotAnObject),
+        }
+    }
+
+    /// Insert a value in this object, returning the previous one
+    pub fn insert(
+        &mut self,
+        key: &str,
+        value: MessyJsonValue<'a>,
+    ) -> Result<Option<MessyJsonValue<'a>>, MessyJsonError> {
+        let settings = self.settings;
+        let (schema, obj) = self.object()?;
+        let (key_str, val_schema) = schema.properties().get_key_value(key).ok_or_else(|| {
+            crate::schema_visitor::messy_json_unknown_key::<serde::de::value::Error>(schema, key)
+        })?;
+        check_value(val_schema, &settings, &value)?;
+        Ok(obj.insert(key_str.clone(), value))
+    }
+
+    /// Remove a key from this object, returning the previous value.
+    ///
+    /// The key is kept as an [absent](MessyJsonNullType::Absent) value, just like
+    /// an optional key missing from a deserialized document.
+    pub fn remove(&mut self, key: &str) -> Result<Option<MessyJsonValue<'a>>, MessyJsonError> {
+        let settings = self.settings;
+        let (schema, obj) = self.object()?;
+        let (key_str, val_schema) = schema.properties().get_key_value(key).ok_or_else(|| {
+            crate::schema_visitor::messy_json_unknown_key::<serde::de::value::Error>(schema, key)
+        })?;
+        if !val_schema.optional() && !settings.all_optional() {
+            return Err(MessyJsonError::Validation(serde::de::Error::custom(
+                format!("Missing key `{}`", key),
+            )));
+        }
+        Ok(obj.insert(
+            key_str.clone(),
+            MessyJsonValue::Null(
+                MessyJsonNullType::Absent,
+                MessyJsonExpected::Root(val_schema.clone()),
+            ),
+        ))
+    }
+
+    /// Look up a value using a [JSON Pointer](https://tools.ietf.org/html/rfc6901),
+    /// keeping track of its schema.
+    pub fn pointer_mut(&mut self, pointer: &str) -> Option<MessyJsonValueMut<'_, 'a>> {
+        let mut schema = &self.schema;
+        let mut value = &mut *self.value;
+        for token in pointer::split(pointer)? {
+            match (schema.deref(), value) {
+                (MessyJsonInner::Obj(obj_schema), MessyJsonValue::Obj(obj)) => {
+                    schema = obj_schema.properties().get(token.as_ref())?;
+                    value = obj.get_mut(token.as_ref())?;
+                }
+                (MessyJsonInner::Array(arr_schema), MessyJsonValue::Array(arr)) => {
+                    schema = arr_schema.items();
+                    value = arr.get_mut(pointer::parse_index(&token)?)?;
+                }
+                _ => return None,
+            }
+        }
+        Some(MessyJsonValueMut::new(schema, value, self.settings))
+    }
+}
+
+impl MessyJson {
+    /// Bind a mutable value to this schema, checking every modification made to it.
+    ///
+    /// See [MessyJsonValueMut](MessyJsonValueMut)
+    #[inline]
+    pub fn edit<'v, 'a>(
+        &self,
+        value: &'v mut MessyJsonValue<'a>,
+        settings: MessyJsonSettings,
+    ) -> MessyJsonValueMut<'v, 'a> {
+        MessyJsonValueMut::new(self, value, settings)
+    }
+}